@@ -1,14 +1,38 @@
-use std::{convert::TryInto, mem};
+use std::{convert::TryInto, io::Cursor, mem};
 
 use bytes::{Buf, BufMut, Bytes, BytesMut};
+use thiserror::Error;
 
-pub(crate) trait Writer {
+use crate::HazelcastClientError;
+
+pub type Result<T> = std::result::Result<T, DecodeError>;
+
+#[derive(Error, Eq, PartialEq, Debug)]
+#[error("unable to decode {expected} at offset {offset}")]
+pub struct DecodeError {
+    offset: usize,
+    expected: &'static str,
+}
+
+impl DecodeError {
+    pub(crate) fn new(offset: usize, expected: &'static str) -> Self {
+        DecodeError { offset, expected }
+    }
+}
+
+impl From<DecodeError> for HazelcastClientError {
+    fn from(error: DecodeError) -> Self {
+        HazelcastClientError::CommunicationFailure(Box::new(error))
+    }
+}
+
+pub trait Writer {
     fn length(&self) -> usize;
 
     fn write_to(&self, writeable: &mut dyn Writeable);
 }
 
-pub(crate) trait Writeable {
+pub trait Writeable {
     fn write_bool(&mut self, value: bool);
 
     fn write_u8(&mut self, value: u8);
@@ -26,28 +50,30 @@ pub(crate) trait Writeable {
     fn write_slice(&mut self, value: &[u8]);
 }
 
-pub(crate) trait Reader {
-    fn read_from(readable: &mut dyn Readable) -> Self;
+pub trait Reader: Sized {
+    fn read_from(readable: &mut dyn Readable) -> Result<Self>;
 }
 
-pub(crate) trait Readable {
-    fn read_bool(&mut self) -> bool;
+pub trait Readable {
+    fn read_bool(&mut self) -> Result<bool>;
+
+    fn read_u8(&mut self) -> Result<u8>;
 
-    fn read_u8(&mut self) -> u8;
+    fn read_u16(&mut self) -> Result<u16>;
 
-    fn read_u16(&mut self) -> u16;
+    fn read_i32(&mut self) -> Result<i32>;
 
-    fn read_i32(&mut self) -> i32;
+    fn read_u32(&mut self) -> Result<u32>;
 
-    fn read_u32(&mut self) -> u32;
+    fn read_i64(&mut self) -> Result<i64>;
 
-    fn read_i64(&mut self) -> i64;
+    fn read_u64(&mut self) -> Result<u64>;
 
-    fn read_u64(&mut self) -> u64;
+    fn read_slice(&mut self, len: usize) -> Result<Bytes>;
 
-    fn read_slice(&mut self, len: usize) -> Bytes;
+    fn offset(&self) -> usize;
 
-    fn skip(&mut self, len: usize);
+    fn remaining(&self) -> usize;
 }
 
 impl Writer for bool {
@@ -185,74 +211,76 @@ impl<T: Writer> Writer for &[T] {
 }
 
 impl Reader for bool {
-    fn read_from(readable: &mut dyn Readable) -> Self {
+    fn read_from(readable: &mut dyn Readable) -> Result<Self> {
         readable.read_bool()
     }
 }
 
 impl Reader for u8 {
-    fn read_from(readable: &mut dyn Readable) -> Self {
+    fn read_from(readable: &mut dyn Readable) -> Result<Self> {
         readable.read_u8()
     }
 }
 
 impl Reader for u16 {
-    fn read_from(readable: &mut dyn Readable) -> Self {
+    fn read_from(readable: &mut dyn Readable) -> Result<Self> {
         readable.read_u16()
     }
 }
 
 impl Reader for i32 {
-    fn read_from(readable: &mut dyn Readable) -> Self {
+    fn read_from(readable: &mut dyn Readable) -> Result<Self> {
         readable.read_i32()
     }
 }
 
 impl Reader for u32 {
-    fn read_from(readable: &mut dyn Readable) -> Self {
+    fn read_from(readable: &mut dyn Readable) -> Result<Self> {
         readable.read_u32()
     }
 }
 
 impl Reader for i64 {
-    fn read_from(readable: &mut dyn Readable) -> Self {
+    fn read_from(readable: &mut dyn Readable) -> Result<Self> {
         readable.read_i64()
     }
 }
 
 impl Reader for u64 {
-    fn read_from(readable: &mut dyn Readable) -> Self {
+    fn read_from(readable: &mut dyn Readable) -> Result<Self> {
         readable.read_u64()
     }
 }
 
 impl Reader for String {
-    fn read_from(readable: &mut dyn Readable) -> Self {
-        let len = readable.read_u32().try_into().expect("unable to convert!");
-        std::str::from_utf8(&readable.read_slice(len))
-            .expect("unable to parse utf8 string!")
-            .to_string()
+    fn read_from(readable: &mut dyn Readable) -> Result<Self> {
+        let offset = readable.offset();
+        let len = readable.read_u32()?.try_into().expect("unable to convert!");
+        match std::str::from_utf8(&readable.read_slice(len)?) {
+            Ok(value) => Ok(value.to_string()),
+            Err(_) => Err(DecodeError::new(offset, "utf8 string")),
+        }
     }
 }
 
 impl<T: Reader> Reader for Option<T> {
-    fn read_from(readable: &mut dyn Readable) -> Self {
-        if !bool::read_from(readable) {
-            Some(T::read_from(readable))
+    fn read_from(readable: &mut dyn Readable) -> Result<Self> {
+        if !bool::read_from(readable)? {
+            Ok(Some(T::read_from(readable)?))
         } else {
-            None
+            Ok(None)
         }
     }
 }
 
 impl<T: Reader> Reader for Vec<T> {
-    fn read_from(readable: &mut dyn Readable) -> Self {
-        let len = u32::read_from(readable).try_into().expect("unable to convert!");
-        let mut items = Vec::with_capacity(len);
+    fn read_from(readable: &mut dyn Readable) -> Result<Self> {
+        let len: usize = u32::read_from(readable)?.try_into().expect("unable to convert!");
+        let mut items = Vec::with_capacity(len.min(readable.remaining()));
         for _ in 0..len {
-            items.push(T::read_from(readable));
+            items.push(T::read_from(readable)?);
         }
-        items
+        Ok(items)
     }
 }
 
@@ -294,41 +322,64 @@ impl Writeable for BytesMut {
     }
 }
 
-impl Readable for Bytes {
-    fn read_bool(&mut self) -> bool {
-        self.read_u8() > 0
+impl Readable for Cursor<Bytes> {
+    fn read_bool(&mut self) -> Result<bool> {
+        ensure(self, mem::size_of::<u8>(), "bool")?;
+        Ok(self.get_u8() > 0)
     }
 
-    fn read_u8(&mut self) -> u8 {
-        self.get_u8()
+    fn read_u8(&mut self) -> Result<u8> {
+        ensure(self, mem::size_of::<u8>(), "u8")?;
+        Ok(self.get_u8())
     }
 
-    fn read_u16(&mut self) -> u16 {
-        self.get_u16_le()
+    fn read_u16(&mut self) -> Result<u16> {
+        ensure(self, mem::size_of::<u16>(), "u16")?;
+        Ok(self.get_u16_le())
     }
 
-    fn read_i32(&mut self) -> i32 {
-        self.get_i32_le()
+    fn read_i32(&mut self) -> Result<i32> {
+        ensure(self, mem::size_of::<i32>(), "i32")?;
+        Ok(self.get_i32_le())
     }
 
-    fn read_u32(&mut self) -> u32 {
-        self.get_u32_le()
+    fn read_u32(&mut self) -> Result<u32> {
+        ensure(self, mem::size_of::<u32>(), "u32")?;
+        Ok(self.get_u32_le())
     }
 
-    fn read_i64(&mut self) -> i64 {
-        self.get_i64_le()
+    fn read_i64(&mut self) -> Result<i64> {
+        ensure(self, mem::size_of::<i64>(), "i64")?;
+        Ok(self.get_i64_le())
     }
 
-    fn read_u64(&mut self) -> u64 {
-        self.get_u64_le()
+    fn read_u64(&mut self) -> Result<u64> {
+        ensure(self, mem::size_of::<u64>(), "u64")?;
+        Ok(self.get_u64_le())
     }
 
-    fn read_slice(&mut self, len: usize) -> Bytes {
-        self.split_to(len)
+    fn read_slice(&mut self, len: usize) -> Result<Bytes> {
+        ensure(self, len, "slice")?;
+        let offset = Readable::offset(self);
+        let slice = self.get_ref().slice(offset..offset + len);
+        self.advance(len);
+        Ok(slice)
     }
 
-    fn skip(&mut self, len: usize) {
-        self.advance(len);
+    fn offset(&self) -> usize {
+        self.position().try_into().expect("unable to convert!")
+    }
+
+    fn remaining(&self) -> usize {
+        Buf::remaining(self)
+    }
+}
+
+fn ensure(readable: &Cursor<Bytes>, len: usize, expected: &'static str) -> Result<()> {
+    if Buf::remaining(readable) < len {
+        Err(DecodeError::new(Readable::offset(readable), expected))
+    } else {
+        Ok(())
     }
 }
 
@@ -346,9 +397,9 @@ mod tests {
         true.write_to(writeable);
         false.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(bool::read_from(readable), true);
-        assert_eq!(bool::read_from(readable), false);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert!(bool::read_from(readable).unwrap());
+        assert!(!bool::read_from(readable).unwrap());
     }
 
     #[test]
@@ -357,9 +408,9 @@ mod tests {
         1u8.write_to(writeable);
         0u8.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(u8::read_from(readable), 1);
-        assert_eq!(u8::read_from(readable), 0);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(u8::read_from(readable).unwrap(), 1);
+        assert_eq!(u8::read_from(readable).unwrap(), 0);
     }
 
     #[test]
@@ -368,9 +419,9 @@ mod tests {
         1u16.write_to(writeable);
         0u16.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(u16::read_from(readable), 1);
-        assert_eq!(u16::read_from(readable), 0);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(u16::read_from(readable).unwrap(), 1);
+        assert_eq!(u16::read_from(readable).unwrap(), 0);
     }
 
     #[test]
@@ -379,9 +430,9 @@ mod tests {
         (-1i32).write_to(writeable);
         1i32.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(i32::read_from(readable), -1);
-        assert_eq!(i32::read_from(readable), 1);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(i32::read_from(readable).unwrap(), -1);
+        assert_eq!(i32::read_from(readable).unwrap(), 1);
     }
 
     #[test]
@@ -390,9 +441,9 @@ mod tests {
         1u32.write_to(writeable);
         0u32.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(u32::read_from(readable), 1);
-        assert_eq!(u32::read_from(readable), 0);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(u32::read_from(readable).unwrap(), 1);
+        assert_eq!(u32::read_from(readable).unwrap(), 0);
     }
 
     #[test]
@@ -401,9 +452,9 @@ mod tests {
         (-1i64).write_to(writeable);
         1i64.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(i64::read_from(readable), -1);
-        assert_eq!(i64::read_from(readable), 1);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(i64::read_from(readable).unwrap(), -1);
+        assert_eq!(i64::read_from(readable).unwrap(), 1);
     }
 
     #[test]
@@ -412,9 +463,9 @@ mod tests {
         1u64.write_to(writeable);
         0u64.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(u64::read_from(readable), 1);
-        assert_eq!(u64::read_from(readable), 0);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(u64::read_from(readable).unwrap(), 1);
+        assert_eq!(u64::read_from(readable).unwrap(), 0);
     }
 
     #[test]
@@ -422,19 +473,9 @@ mod tests {
         let writeable = &mut BytesMut::new();
         [1, 0].write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(readable.read_slice(1)[..], [1]);
-        assert_eq!(readable.read_slice(1)[..], [0]);
-    }
-
-    #[test]
-    fn should_skip() {
-        let writeable = &mut BytesMut::new();
-        [1, 0, 1].write_to(writeable);
-
-        let readable = &mut writeable.to_bytes();
-        readable.skip(1);
-        assert_eq!(readable.read_slice(2)[..], [0, 1]);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(readable.read_slice(1).unwrap()[..], [1]);
+        assert_eq!(readable.read_slice(1).unwrap()[..], [0]);
     }
 
     #[test]
@@ -442,8 +483,8 @@ mod tests {
         let writeable = &mut BytesMut::new();
         "10".write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(String::read_from(readable), "10");
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), "10");
     }
 
     #[test]
@@ -452,9 +493,9 @@ mod tests {
         Some(1u32).write_to(writeable);
         Option::<u32>::None.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(Option::read_from(readable), Some(1u32));
-        assert_eq!(Option::<u32>::read_from(readable), None);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(Option::read_from(readable).unwrap(), Some(1u32));
+        assert_eq!(Option::<u32>::read_from(readable).unwrap(), None);
     }
 
     #[test]
@@ -462,7 +503,31 @@ mod tests {
         let writeable = &mut BytesMut::new();
         vec![1u32].deref().write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(Vec::<u32>::read_from(readable), vec!(1u32));
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(Vec::<u32>::read_from(readable).unwrap(), vec!(1u32));
+    }
+
+    #[test]
+    fn should_fail_to_read_from_short_input() {
+        let writeable = &mut BytesMut::new();
+        1u8.write_to(writeable);
+        1u16.write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(u8::read_from(readable), Ok(1));
+        assert_eq!(u64::read_from(readable), Err(DecodeError::new(1, "u64")));
+        assert_eq!(Vec::<u32>::read_from(readable), Err(DecodeError::new(1, "u32")));
+    }
+
+    #[test]
+    fn should_fail_to_read_invalid_str() {
+        let writeable = &mut BytesMut::new();
+        [0u8; 1].write_to(writeable);
+        2u32.write_to(writeable);
+        [0xC3, 0x28].write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        readable.read_u8().unwrap();
+        assert_eq!(String::read_from(readable), Err(DecodeError::new(1, "utf8 string")));
     }
 }
@@ -0,0 +1,209 @@
+use std::{str::FromStr, time::Duration};
+
+use crate::{
+    config::{resolve, ClientConfigBuilder, RetryPolicy, Ssl},
+    HazelcastClientError::InvalidConfiguration,
+    Result,
+};
+
+const PREFIX: &str = "HZ_";
+const SEPARATOR: char = ',';
+
+pub(super) fn apply<V>(mut builder: ClientConfigBuilder, variables: V) -> Result<ClientConfigBuilder>
+where
+    V: IntoIterator<Item = (String, String)>,
+{
+    let mut ssl = Ssl::default();
+    for (key, value) in variables {
+        let config = &mut builder.config;
+        match key.strip_prefix(PREFIX) {
+            Some("INSTANCE_NAME") => config.name = value,
+            Some("GROUP_NAME") => config.group_name = value,
+            Some("GROUP_PASSWORD") => config.group_password = value,
+            Some("CLIENT_LABELS") => config.labels = split(&value).map(str::to_string).collect(),
+            Some("NETWORK_CLUSTER_MEMBERS") => config.addresses = split(&value).map(resolve).collect::<Result<_>>()?,
+            Some("NETWORK_SMART_ROUTING") => config.smart_routing = parse(&key, &value)?,
+            Some("NETWORK_REDO_OPERATION") => config.redo_operation = parse(&key, &value)?,
+            Some("NETWORK_CONNECTION_TIMEOUT") => {
+                config.connection_timeout = Duration::from_millis(parse(&key, &value)?);
+            }
+            Some("NETWORK_SSL_ENABLED") => ssl.enabled = Some(parse(&key, &value)?),
+            Some("NETWORK_SSL_CA_BUNDLES") => ssl.ca_bundles = split(&value).map(str::to_string).collect(),
+            Some("NETWORK_SSL_CERTIFICATE") => ssl.certificate = Some(value),
+            Some("NETWORK_SSL_PRIVATE_KEY") => ssl.private_key = Some(value),
+            Some("NETWORK_SSL_VERIFY_HOSTNAME") => ssl.verify_hostname = Some(parse(&key, &value)?),
+            Some("NETWORK_SSL_SERVER_NAME") => ssl.server_name = Some(value),
+            Some("CONNECTION_RETRY_INITIAL_BACKOFF_MILLIS") => {
+                let retry_policy = &config.retry_policy;
+                config.retry_policy = RetryPolicy::new(
+                    Duration::from_millis(parse(&key, &value)?),
+                    retry_policy.max_backoff(),
+                    retry_policy.multiplier(),
+                );
+            }
+            Some("CONNECTION_RETRY_MAX_BACKOFF_MILLIS") => {
+                let retry_policy = &config.retry_policy;
+                config.retry_policy = RetryPolicy::new(
+                    retry_policy.initial_backoff(),
+                    Duration::from_millis(parse(&key, &value)?),
+                    retry_policy.multiplier(),
+                );
+            }
+            Some("CONNECTION_RETRY_MULTIPLIER") => {
+                let retry_policy = &config.retry_policy;
+                config.retry_policy = RetryPolicy::new(
+                    retry_policy.initial_backoff(),
+                    retry_policy.max_backoff(),
+                    parse(&key, &value)?,
+                );
+            }
+            Some("CLIENT_HEARTBEAT_INTERVAL") => {
+                config.heartbeat_interval = Duration::from_millis(parse(&key, &value)?);
+            }
+            Some("CLIENT_HEARTBEAT_TIMEOUT") => {
+                config.heartbeat_timeout = Duration::from_millis(parse(&key, &value)?);
+            }
+            Some("CLIENT_INVOCATION_TIMEOUT_SECONDS") => {
+                config.invocation_timeout = Duration::from_secs(parse(&key, &value)?);
+            }
+            Some("CLIENT_SHUTDOWN_TIMEOUT") => {
+                config.shutdown_timeout = Duration::from_millis(parse(&key, &value)?);
+            }
+            Some("CLIENT_FRAME_SIZE") => config.frame_size = parse(&key, &value)?,
+            Some("CLIENT_EGRESS_QUEUE_CAPACITY") => config.egress_queue_capacity = parse(&key, &value)?,
+            Some("CLIENT_MAX_CONCURRENT_INVOCATIONS") => config.max_concurrent_invocations = parse(&key, &value)?,
+            Some("CLIENT_FAIL_ON_OVERLOAD") => config.fail_on_overload = parse(&key, &value)?,
+            _ => {}
+        }
+    }
+    ssl.apply(builder)
+}
+
+fn split(value: &str) -> impl Iterator<Item = &str> {
+    value.split(SEPARATOR).map(str::trim).filter(|item| !item.is_empty())
+}
+
+fn parse<T: FromStr>(key: &str, value: &str) -> Result<T> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| InvalidConfiguration(format!("invalid value of {}: {}", key, value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+    #[cfg(feature = "tls")]
+    use std::path::PathBuf;
+
+    use crate::config::ClientConfig;
+
+    use super::*;
+
+    #[test]
+    fn should_override_with_variables() {
+        let builder = ClientConfig::builder()
+            .address("10.0.0.1:5701".parse().unwrap())
+            .group_name("some-group")
+            .label("some-label");
+
+        let config = apply(
+            builder,
+            variables(&[
+                ("HZ_INSTANCE_NAME", "other-name"),
+                ("HZ_GROUP_NAME", "other-group"),
+                ("HZ_CLIENT_LABELS", "first, second"),
+                ("HZ_NETWORK_CLUSTER_MEMBERS", "10.0.0.2:5702,10.0.0.3"),
+                ("HZ_NETWORK_SMART_ROUTING", "false"),
+                ("HZ_NETWORK_REDO_OPERATION", "true"),
+                ("HZ_CONNECTION_RETRY_MULTIPLIER", "1.5"),
+                ("HZ_CLIENT_INVOCATION_TIMEOUT_SECONDS", "30"),
+                ("HZ_CLIENT_MAX_CONCURRENT_INVOCATIONS", "64"),
+                ("HZ_CLIENT_FAIL_ON_OVERLOAD", "true"),
+            ]),
+        )
+        .unwrap()
+        .build();
+
+        assert_eq!(config.name(), "other-name");
+        assert_eq!(config.group_name(), "other-group");
+        assert_eq!(config.labels(), ["first".to_string(), "second".to_string()]);
+        assert_eq!(
+            config.addresses(),
+            [
+                "10.0.0.2:5702".parse::<SocketAddr>().unwrap(),
+                "10.0.0.3:5701".parse().unwrap()
+            ]
+        );
+        assert!(!config.smart_routing());
+        assert!(config.redo_operation());
+        assert_eq!(config.retry_policy().multiplier(), 1.5);
+        assert_eq!(config.invocation_timeout(), Duration::from_secs(30));
+        assert_eq!(config.max_concurrent_invocations(), 64);
+        assert!(config.fail_on_overload());
+    }
+
+    #[test]
+    fn should_ignore_unrelated_variables() {
+        let config = apply(
+            ClientConfig::builder(),
+            variables(&[("PATH", "/bin"), ("HZ_UNKNOWN", "value"), ("GROUP_NAME", "group")]),
+        )
+        .unwrap()
+        .build();
+
+        assert_eq!(config, ClientConfig::builder().build());
+    }
+
+    #[test]
+    fn should_fail_on_invalid_value() {
+        assert!(apply(
+            ClientConfig::builder(),
+            variables(&[("HZ_NETWORK_SMART_ROUTING", "maybe")])
+        )
+        .is_err());
+        assert!(apply(
+            ClientConfig::builder(),
+            variables(&[("HZ_CLIENT_HEARTBEAT_INTERVAL", "-1")])
+        )
+        .is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "tls")]
+    fn should_configure_ssl_with_variables() {
+        let config = apply(
+            ClientConfig::builder(),
+            variables(&[
+                ("HZ_NETWORK_SSL_CA_BUNDLES", "first.pem, second.pem"),
+                ("HZ_NETWORK_SSL_SERVER_NAME", "hazelcast.example"),
+                ("HZ_NETWORK_SSL_ENABLED", "true"),
+            ]),
+        )
+        .unwrap()
+        .build();
+
+        let tls = config.tls().unwrap();
+        assert_eq!(
+            tls.ca_bundles(),
+            [PathBuf::from("first.pem"), PathBuf::from("second.pem")]
+        );
+        assert_eq!(tls.server_name(), Some("hazelcast.example"));
+        assert!(tls.verify_hostname());
+        assert!(apply(
+            ClientConfig::builder(),
+            variables(&[("HZ_NETWORK_SSL_SERVER_NAME", "hazelcast.example")])
+        )
+        .unwrap()
+        .build()
+        .tls()
+        .is_none());
+    }
+
+    fn variables(variables: &[(&str, &str)]) -> Vec<(String, String)> {
+        variables
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+}
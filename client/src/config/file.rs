@@ -0,0 +1,361 @@
+use std::{collections::HashMap, time::Duration};
+
+use serde::Deserialize;
+
+use crate::{
+    config::{resolve, ClientConfigBuilder, RetryPolicy, Ssl},
+    HazelcastClientError::InvalidConfiguration,
+    Result,
+};
+
+const HEARTBEAT_INTERVAL_PROPERTY: &str = "hazelcast.client.heartbeat.interval";
+const HEARTBEAT_TIMEOUT_PROPERTY: &str = "hazelcast.client.heartbeat.timeout";
+const INVOCATION_TIMEOUT_PROPERTY: &str = "hazelcast.client.invocation.timeout.seconds";
+const SHUTDOWN_TIMEOUT_PROPERTY: &str = "hazelcast.client.shutdown.timeout";
+const FRAME_SIZE_PROPERTY: &str = "hazelcast.client.frame.size";
+const EGRESS_QUEUE_CAPACITY_PROPERTY: &str = "hazelcast.client.egress.queue.capacity";
+const MAX_CONCURRENT_INVOCATIONS_PROPERTY: &str = "hazelcast.client.max.concurrent.invocations";
+const FAIL_ON_OVERLOAD_PROPERTY: &str = "hazelcast.client.fail.on.overload";
+
+pub(super) fn from_toml(content: &str) -> Result<ClientConfigBuilder> {
+    match toml::from_str::<File>(content) {
+        Ok(file) => file.hazelcast_client.apply(ClientConfigBuilder::new()),
+        Err(e) => Err(InvalidConfiguration(e.to_string())),
+    }
+}
+
+pub(super) fn from_yaml(content: &str) -> Result<ClientConfigBuilder> {
+    match serde_yaml::from_str::<File>(content) {
+        Ok(file) => file.hazelcast_client.apply(ClientConfigBuilder::new()),
+        Err(e) => Err(InvalidConfiguration(e.to_string())),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct File {
+    hazelcast_client: Client,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "kebab-case", default)]
+struct Client {
+    instance_name: Option<String>,
+    group: Group,
+    client_labels: Option<Vec<String>>,
+    network: Network,
+    connection_strategy: ConnectionStrategy,
+    properties: HashMap<String, Property>,
+}
+
+impl Client {
+    fn apply(self, mut builder: ClientConfigBuilder) -> Result<ClientConfigBuilder> {
+        if let Some(name) = self.instance_name {
+            builder.config.name = name;
+        }
+        if let Some(name) = self.group.name {
+            builder.config.group_name = name;
+        }
+        if let Some(password) = self.group.password {
+            builder.config.group_password = password;
+        }
+        if let Some(labels) = self.client_labels {
+            builder.config.labels = labels;
+        }
+        if let Some(members) = &self.network.cluster_members {
+            builder.config.addresses = members.iter().map(|member| resolve(member)).collect::<Result<_>>()?;
+        }
+        if let Some(smart_routing) = self.network.smart_routing {
+            builder.config.smart_routing = smart_routing;
+        }
+        if let Some(redo_operation) = self.network.redo_operation {
+            builder.config.redo_operation = redo_operation;
+        }
+        if let Some(connection_timeout) = self.network.connection_timeout {
+            builder.config.connection_timeout = Duration::from_millis(connection_timeout);
+        }
+        builder = self.network.ssl.apply(builder)?;
+
+        let retry = self.connection_strategy.connection_retry;
+        let retry_policy = &builder.config.retry_policy;
+        builder.config.retry_policy = RetryPolicy::new(
+            retry
+                .initial_backoff_millis
+                .map(Duration::from_millis)
+                .unwrap_or_else(|| retry_policy.initial_backoff()),
+            retry
+                .max_backoff_millis
+                .map(Duration::from_millis)
+                .unwrap_or_else(|| retry_policy.max_backoff()),
+            retry.multiplier.unwrap_or_else(|| retry_policy.multiplier()),
+        );
+
+        if let Some(property) = self.properties.get(HEARTBEAT_INTERVAL_PROPERTY) {
+            builder.config.heartbeat_interval = Duration::from_millis(property.as_u64(HEARTBEAT_INTERVAL_PROPERTY)?);
+        }
+        if let Some(property) = self.properties.get(HEARTBEAT_TIMEOUT_PROPERTY) {
+            builder.config.heartbeat_timeout = Duration::from_millis(property.as_u64(HEARTBEAT_TIMEOUT_PROPERTY)?);
+        }
+        if let Some(property) = self.properties.get(INVOCATION_TIMEOUT_PROPERTY) {
+            builder.config.invocation_timeout = Duration::from_secs(property.as_u64(INVOCATION_TIMEOUT_PROPERTY)?);
+        }
+        if let Some(property) = self.properties.get(SHUTDOWN_TIMEOUT_PROPERTY) {
+            builder.config.shutdown_timeout = Duration::from_millis(property.as_u64(SHUTDOWN_TIMEOUT_PROPERTY)?);
+        }
+        if let Some(property) = self.properties.get(FRAME_SIZE_PROPERTY) {
+            builder.config.frame_size = property.as_u64(FRAME_SIZE_PROPERTY)? as usize;
+        }
+        if let Some(property) = self.properties.get(EGRESS_QUEUE_CAPACITY_PROPERTY) {
+            builder.config.egress_queue_capacity = property.as_u64(EGRESS_QUEUE_CAPACITY_PROPERTY)? as usize;
+        }
+        if let Some(property) = self.properties.get(MAX_CONCURRENT_INVOCATIONS_PROPERTY) {
+            builder.config.max_concurrent_invocations = property.as_u64(MAX_CONCURRENT_INVOCATIONS_PROPERTY)? as usize;
+        }
+        if let Some(property) = self.properties.get(FAIL_ON_OVERLOAD_PROPERTY) {
+            builder.config.fail_on_overload = property.as_bool(FAIL_ON_OVERLOAD_PROPERTY)?;
+        }
+
+        Ok(builder)
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "kebab-case", default)]
+struct Group {
+    name: Option<String>,
+    password: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "kebab-case", default)]
+struct Network {
+    cluster_members: Option<Vec<String>>,
+    smart_routing: Option<bool>,
+    redo_operation: Option<bool>,
+    connection_timeout: Option<u64>,
+    ssl: Ssl,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "kebab-case", default)]
+struct ConnectionStrategy {
+    connection_retry: ConnectionRetry,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "kebab-case", default)]
+struct ConnectionRetry {
+    initial_backoff_millis: Option<u64>,
+    max_backoff_millis: Option<u64>,
+    multiplier: Option<f64>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Property {
+    Flag(bool),
+    Number(u64),
+    Text(String),
+}
+
+impl Property {
+    fn as_u64(&self, name: &str) -> Result<u64> {
+        match self {
+            Property::Number(value) => Ok(*value),
+            Property::Text(value) => value
+                .parse()
+                .map_err(|_| InvalidConfiguration(format!("invalid value of {}: {}", name, value))),
+            Property::Flag(value) => Err(InvalidConfiguration(format!("invalid value of {}: {}", name, value))),
+        }
+    }
+
+    fn as_bool(&self, name: &str) -> Result<bool> {
+        match self {
+            Property::Flag(value) => Ok(*value),
+            Property::Text(value) => value
+                .parse()
+                .map_err(|_| InvalidConfiguration(format!("invalid value of {}: {}", name, value))),
+            Property::Number(value) => Err(InvalidConfiguration(format!("invalid value of {}: {}", name, value))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::ClientConfig;
+
+    use super::*;
+
+    #[test]
+    fn should_read_yaml() {
+        let content = r#"
+hazelcast-client:
+  instance-name: some-name
+  group:
+    name: some-group
+    password: some-password
+  client-labels:
+    - some-label
+  network:
+    cluster-members:
+      - 10.0.0.1:5701
+      - 10.0.0.2
+    smart-routing: false
+    redo-operation: true
+    connection-timeout: 1000
+  connection-strategy:
+    connection-retry:
+      initial-backoff-millis: 100
+      max-backoff-millis: 5000
+      multiplier: 1.5
+  properties:
+    hazelcast.client.heartbeat.interval: 2000
+    hazelcast.client.heartbeat.timeout: 20000
+    hazelcast.client.invocation.timeout.seconds: "60"
+    hazelcast.client.shutdown.timeout: 3000
+    hazelcast.client.frame.size: 4096
+    hazelcast.client.egress.queue.capacity: 16
+    hazelcast.client.max.concurrent.invocations: 32
+    hazelcast.client.fail.on.overload: true
+"#;
+
+        assert_eq!(from_yaml(content).unwrap().build(), expected_config());
+    }
+
+    #[test]
+    fn should_read_toml() {
+        let content = r#"
+[hazelcast-client]
+instance-name = "some-name"
+client-labels = ["some-label"]
+
+[hazelcast-client.group]
+name = "some-group"
+password = "some-password"
+
+[hazelcast-client.network]
+cluster-members = ["10.0.0.1:5701", "10.0.0.2"]
+smart-routing = false
+redo-operation = true
+connection-timeout = 1000
+
+[hazelcast-client.connection-strategy.connection-retry]
+initial-backoff-millis = 100
+max-backoff-millis = 5000
+multiplier = 1.5
+
+[hazelcast-client.properties]
+"hazelcast.client.heartbeat.interval" = 2000
+"hazelcast.client.heartbeat.timeout" = 20000
+"hazelcast.client.invocation.timeout.seconds" = "60"
+"hazelcast.client.shutdown.timeout" = 3000
+"hazelcast.client.frame.size" = 4096
+"hazelcast.client.egress.queue.capacity" = 16
+"hazelcast.client.max.concurrent.invocations" = 32
+"hazelcast.client.fail.on.overload" = "true"
+"#;
+
+        assert_eq!(from_toml(content).unwrap().build(), expected_config());
+    }
+
+    #[test]
+    fn should_keep_defaults_for_missing_sections() {
+        assert_eq!(
+            from_yaml("hazelcast-client: {}").unwrap().build(),
+            ClientConfig::builder().build()
+        );
+    }
+
+    #[test]
+    fn should_replace_list_settings() {
+        let content = r#"
+hazelcast-client:
+  client-labels:
+    - other-label
+  network:
+    cluster-members:
+      - 10.0.0.2
+"#;
+        let client = serde_yaml::from_str::<File>(content).unwrap().hazelcast_client;
+        let builder = ClientConfig::builder()
+            .label("some-label")
+            .address("10.0.0.1:5701".parse().unwrap());
+
+        let config = client.apply(builder).unwrap().build();
+
+        assert_eq!(config.labels(), ["other-label".to_string()]);
+        assert_eq!(config.addresses(), ["10.0.0.2:5701".parse().unwrap()]);
+    }
+
+    #[test]
+    fn should_fail_on_invalid_content() {
+        assert!(from_yaml("hazelcast-client: [").is_err());
+        assert!(from_toml("[hazelcast-client.network]\nsmart-routing = 1").is_err());
+        assert!(from_yaml("hazelcast-client:\n  properties:\n    hazelcast.client.heartbeat.interval: x").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "tls")]
+    fn should_read_ssl() {
+        let content = r#"
+hazelcast-client:
+  network:
+    ssl:
+      enabled: true
+      ca-bundles:
+        - ca.pem
+      certificate: client.pem
+      private-key: client.key
+      verify-hostname: false
+      server-name: hazelcast.example
+"#;
+
+        assert_eq!(
+            from_yaml(content).unwrap().build(),
+            ClientConfig::builder()
+                .tls_ca_bundle("ca.pem")
+                .tls_client_certificate("client.pem", "client.key")
+                .tls_verify_hostname(false)
+                .tls_server_name("hazelcast.example")
+                .build()
+        );
+        assert!(
+            from_yaml("hazelcast-client:\n  network:\n    ssl:\n      enabled: true\n      certificate: a.pem")
+                .is_err()
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "tls"))]
+    fn should_fail_on_ssl_without_tls_feature() {
+        assert!(from_yaml("hazelcast-client:\n  network:\n    ssl:\n      enabled: true").is_err());
+        assert!(from_yaml("hazelcast-client:\n  network:\n    ssl:\n      enabled: false").is_ok());
+    }
+
+    fn expected_config() -> ClientConfig {
+        ClientConfig::builder()
+            .name("some-name")
+            .group_name("some-group")
+            .group_password("some-password")
+            .label("some-label")
+            .address("10.0.0.1:5701".parse().unwrap())
+            .address("10.0.0.2:5701".parse().unwrap())
+            .smart_routing(false)
+            .redo_operation(true)
+            .connection_timeout(Duration::from_secs(1))
+            .retry_policy(RetryPolicy::new(
+                Duration::from_millis(100),
+                Duration::from_secs(5),
+                1.5,
+            ))
+            .heartbeat_interval(Duration::from_secs(2))
+            .heartbeat_timeout(Duration::from_secs(20))
+            .invocation_timeout(Duration::from_secs(60))
+            .shutdown_timeout(Duration::from_secs(3))
+            .frame_size(4096)
+            .egress_queue_capacity(16)
+            .max_concurrent_invocations(32)
+            .fail_on_overload(true)
+            .build()
+    }
+}
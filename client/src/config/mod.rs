@@ -0,0 +1,600 @@
+#[cfg(feature = "tls")]
+use std::path::PathBuf;
+use std::{
+    fs,
+    net::{SocketAddr, ToSocketAddrs},
+    path::Path,
+    time::Duration,
+};
+
+use serde::Deserialize;
+
+use crate::{HazelcastClientError::InvalidConfiguration, Result};
+
+mod env;
+mod file;
+
+const DEFAULT_NAME: &str = "hz.client_0";
+const DEFAULT_GROUP_NAME: &str = "dev";
+const DEFAULT_GROUP_PASSWORD: &str = "dev-pass";
+const DEFAULT_ADDRESS: &str = "127.0.0.1:5701";
+const DEFAULT_PORT: u16 = 5701;
+const DEFAULT_CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+const DEFAULT_INVOCATION_TIMEOUT: Duration = Duration::from_secs(120);
+const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_FRAME_SIZE: usize = 1 << 20;
+const MIN_FRAME_SIZE: usize = 64;
+const DEFAULT_EGRESS_QUEUE_CAPACITY: usize = 1024;
+const DEFAULT_MAX_CONCURRENT_INVOCATIONS: usize = i32::MAX as usize;
+const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+const MIN_HEARTBEAT_INTERVAL: Duration = Duration::from_millis(1);
+const DEFAULT_HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(60);
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+const DEFAULT_BACKOFF_MULTIPLIER: f64 = 2.0;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ClientConfig {
+    name: String,
+    group_name: String,
+    group_password: String,
+    addresses: Vec<SocketAddr>,
+    connection_timeout: Duration,
+    invocation_timeout: Duration,
+    shutdown_timeout: Duration,
+    frame_size: usize,
+    egress_queue_capacity: usize,
+    max_concurrent_invocations: usize,
+    fail_on_overload: bool,
+    heartbeat_interval: Duration,
+    heartbeat_timeout: Duration,
+    retry_policy: RetryPolicy,
+    smart_routing: bool,
+    redo_operation: bool,
+    labels: Vec<String>,
+    #[cfg(feature = "tls")]
+    tls: Option<TlsConfig>,
+}
+
+impl ClientConfig {
+    pub fn builder() -> ClientConfigBuilder {
+        ClientConfigBuilder::new()
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|e| InvalidConfiguration(format!("unable to read {} - {}", path.display(), e)))?;
+        let builder = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => file::from_toml(&content)?,
+            Some("yaml") | Some("yml") => file::from_yaml(&content)?,
+            _ => {
+                return Err(InvalidConfiguration(format!(
+                    "unsupported format of {}",
+                    path.display()
+                )))
+            }
+        };
+        Ok(env::apply(builder, std::env::vars())?.build())
+    }
+
+    pub fn from_env() -> Result<Self> {
+        Ok(env::apply(ClientConfigBuilder::new(), std::env::vars())?.build())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn group_name(&self) -> &str {
+        &self.group_name
+    }
+
+    pub fn group_password(&self) -> &str {
+        &self.group_password
+    }
+
+    pub fn addresses(&self) -> &[SocketAddr] {
+        &self.addresses
+    }
+
+    pub fn connection_timeout(&self) -> Duration {
+        self.connection_timeout
+    }
+
+    pub fn invocation_timeout(&self) -> Duration {
+        self.invocation_timeout
+    }
+
+    pub fn shutdown_timeout(&self) -> Duration {
+        self.shutdown_timeout
+    }
+
+    pub fn frame_size(&self) -> usize {
+        self.frame_size
+    }
+
+    pub fn egress_queue_capacity(&self) -> usize {
+        self.egress_queue_capacity
+    }
+
+    pub fn max_concurrent_invocations(&self) -> usize {
+        self.max_concurrent_invocations
+    }
+
+    pub fn fail_on_overload(&self) -> bool {
+        self.fail_on_overload
+    }
+
+    pub fn heartbeat_interval(&self) -> Duration {
+        self.heartbeat_interval
+    }
+
+    pub fn heartbeat_timeout(&self) -> Duration {
+        self.heartbeat_timeout
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    pub fn smart_routing(&self) -> bool {
+        self.smart_routing
+    }
+
+    pub fn redo_operation(&self) -> bool {
+        self.redo_operation
+    }
+
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    #[cfg(feature = "tls")]
+    pub fn tls(&self) -> Option<&TlsConfig> {
+        self.tls.as_ref()
+    }
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        ClientConfig {
+            name: DEFAULT_NAME.to_string(),
+            group_name: DEFAULT_GROUP_NAME.to_string(),
+            group_password: DEFAULT_GROUP_PASSWORD.to_string(),
+            addresses: vec![],
+            connection_timeout: DEFAULT_CONNECTION_TIMEOUT,
+            invocation_timeout: DEFAULT_INVOCATION_TIMEOUT,
+            shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+            frame_size: DEFAULT_FRAME_SIZE,
+            egress_queue_capacity: DEFAULT_EGRESS_QUEUE_CAPACITY,
+            max_concurrent_invocations: DEFAULT_MAX_CONCURRENT_INVOCATIONS,
+            fail_on_overload: false,
+            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
+            heartbeat_timeout: DEFAULT_HEARTBEAT_TIMEOUT,
+            retry_policy: RetryPolicy::default(),
+            smart_routing: true,
+            redo_operation: false,
+            labels: vec![],
+            #[cfg(feature = "tls")]
+            tls: None,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct RetryPolicy {
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+}
+
+impl RetryPolicy {
+    pub fn new(initial_backoff: Duration, max_backoff: Duration, multiplier: f64) -> Self {
+        RetryPolicy {
+            initial_backoff,
+            max_backoff: max_backoff.max(initial_backoff),
+            multiplier: multiplier.max(1.0),
+        }
+    }
+
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new(DEFAULT_INITIAL_BACKOFF, DEFAULT_MAX_BACKOFF, DEFAULT_BACKOFF_MULTIPLIER)
+    }
+}
+
+#[cfg(feature = "tls")]
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct TlsConfig {
+    ca_bundles: Vec<PathBuf>,
+    client_certificate: Option<(PathBuf, PathBuf)>,
+    verify_hostname: bool,
+    server_name: Option<String>,
+}
+
+#[cfg(feature = "tls")]
+impl TlsConfig {
+    pub fn ca_bundles(&self) -> &[PathBuf] {
+        &self.ca_bundles
+    }
+
+    pub fn client_certificate(&self) -> Option<(&Path, &Path)> {
+        self.client_certificate
+            .as_ref()
+            .map(|(certificate_chain, private_key)| (certificate_chain.as_path(), private_key.as_path()))
+    }
+
+    pub fn verify_hostname(&self) -> bool {
+        self.verify_hostname
+    }
+
+    pub fn server_name(&self) -> Option<&str> {
+        self.server_name.as_deref()
+    }
+}
+
+#[cfg(feature = "tls")]
+impl Default for TlsConfig {
+    fn default() -> Self {
+        TlsConfig {
+            ca_bundles: vec![],
+            client_certificate: None,
+            verify_hostname: true,
+            server_name: None,
+        }
+    }
+}
+
+pub struct ClientConfigBuilder {
+    config: ClientConfig,
+}
+
+impl ClientConfigBuilder {
+    fn new() -> Self {
+        ClientConfigBuilder {
+            config: ClientConfig::default(),
+        }
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.config.name = name.to_string();
+        self
+    }
+
+    pub fn group_name(mut self, group_name: &str) -> Self {
+        self.config.group_name = group_name.to_string();
+        self
+    }
+
+    pub fn group_password(mut self, group_password: &str) -> Self {
+        self.config.group_password = group_password.to_string();
+        self
+    }
+
+    pub fn address(mut self, address: SocketAddr) -> Self {
+        self.config.addresses.push(address);
+        self
+    }
+
+    pub fn addresses<E>(mut self, addresses: E) -> Self
+    where
+        E: IntoIterator<Item = SocketAddr>,
+    {
+        self.config.addresses.extend(addresses);
+        self
+    }
+
+    pub fn connection_timeout(mut self, connection_timeout: Duration) -> Self {
+        self.config.connection_timeout = connection_timeout;
+        self
+    }
+
+    pub fn invocation_timeout(mut self, invocation_timeout: Duration) -> Self {
+        self.config.invocation_timeout = invocation_timeout;
+        self
+    }
+
+    pub fn shutdown_timeout(mut self, shutdown_timeout: Duration) -> Self {
+        self.config.shutdown_timeout = shutdown_timeout;
+        self
+    }
+
+    pub fn frame_size(mut self, frame_size: usize) -> Self {
+        self.config.frame_size = frame_size;
+        self
+    }
+
+    pub fn egress_queue_capacity(mut self, egress_queue_capacity: usize) -> Self {
+        self.config.egress_queue_capacity = egress_queue_capacity;
+        self
+    }
+
+    pub fn max_concurrent_invocations(mut self, max_concurrent_invocations: usize) -> Self {
+        self.config.max_concurrent_invocations = max_concurrent_invocations;
+        self
+    }
+
+    pub fn fail_on_overload(mut self, fail_on_overload: bool) -> Self {
+        self.config.fail_on_overload = fail_on_overload;
+        self
+    }
+
+    pub fn heartbeat_interval(mut self, heartbeat_interval: Duration) -> Self {
+        self.config.heartbeat_interval = heartbeat_interval;
+        self
+    }
+
+    pub fn heartbeat_timeout(mut self, heartbeat_timeout: Duration) -> Self {
+        self.config.heartbeat_timeout = heartbeat_timeout;
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.config.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn smart_routing(mut self, smart_routing: bool) -> Self {
+        self.config.smart_routing = smart_routing;
+        self
+    }
+
+    pub fn redo_operation(mut self, redo_operation: bool) -> Self {
+        self.config.redo_operation = redo_operation;
+        self
+    }
+
+    pub fn label(mut self, label: &str) -> Self {
+        self.config.labels.push(label.to_string());
+        self
+    }
+
+    #[cfg(feature = "tls")]
+    pub fn tls(mut self, enabled: bool) -> Self {
+        self.config.tls = if enabled { Some(TlsConfig::default()) } else { None };
+        self
+    }
+
+    #[cfg(feature = "tls")]
+    pub fn tls_ca_bundle<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.tls_config().ca_bundles.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    #[cfg(feature = "tls")]
+    pub fn tls_client_certificate<P: AsRef<Path>>(mut self, certificate_chain: P, private_key: P) -> Self {
+        self.tls_config().client_certificate = Some((
+            certificate_chain.as_ref().to_path_buf(),
+            private_key.as_ref().to_path_buf(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "tls")]
+    pub fn tls_verify_hostname(mut self, verify_hostname: bool) -> Self {
+        self.tls_config().verify_hostname = verify_hostname;
+        self
+    }
+
+    #[cfg(feature = "tls")]
+    pub fn tls_server_name(mut self, server_name: &str) -> Self {
+        self.tls_config().server_name = Some(server_name.to_string());
+        self
+    }
+
+    #[cfg(feature = "tls")]
+    fn tls_config(&mut self) -> &mut TlsConfig {
+        self.config.tls.get_or_insert_with(TlsConfig::default)
+    }
+
+    pub fn build(mut self) -> ClientConfig {
+        if self.config.addresses.is_empty() {
+            self.config
+                .addresses
+                .push(DEFAULT_ADDRESS.parse().expect("invalid default address!"));
+        }
+        self.config.frame_size = self.config.frame_size.max(MIN_FRAME_SIZE);
+        self.config.heartbeat_interval = self.config.heartbeat_interval.max(MIN_HEARTBEAT_INTERVAL);
+        self.config.egress_queue_capacity = self.config.egress_queue_capacity.max(1);
+        self.config.max_concurrent_invocations = self
+            .config
+            .max_concurrent_invocations
+            .clamp(1, DEFAULT_MAX_CONCURRENT_INVOCATIONS);
+        self.config
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "kebab-case", default)]
+struct Ssl {
+    enabled: Option<bool>,
+    ca_bundles: Vec<String>,
+    certificate: Option<String>,
+    private_key: Option<String>,
+    verify_hostname: Option<bool>,
+    server_name: Option<String>,
+}
+
+impl Ssl {
+    #[cfg(feature = "tls")]
+    fn apply(self, mut builder: ClientConfigBuilder) -> Result<ClientConfigBuilder> {
+        match self.enabled {
+            Some(true) => builder = builder.tls(true),
+            Some(false) => return Ok(builder.tls(false)),
+            None => return Ok(builder),
+        }
+
+        for ca_bundle in &self.ca_bundles {
+            builder = builder.tls_ca_bundle(ca_bundle);
+        }
+        match (&self.certificate, &self.private_key) {
+            (Some(certificate), Some(private_key)) => {
+                builder = builder.tls_client_certificate(certificate, private_key)
+            }
+            (None, None) => {}
+            _ => {
+                return Err(InvalidConfiguration(
+                    "ssl certificate and private key must be configured together".to_string(),
+                ))
+            }
+        }
+        if let Some(verify_hostname) = self.verify_hostname {
+            builder = builder.tls_verify_hostname(verify_hostname);
+        }
+        if let Some(server_name) = &self.server_name {
+            builder = builder.tls_server_name(server_name);
+        }
+        Ok(builder)
+    }
+
+    #[cfg(not(feature = "tls"))]
+    fn apply(self, builder: ClientConfigBuilder) -> Result<ClientConfigBuilder> {
+        match self.enabled {
+            Some(true) => Err(InvalidConfiguration(
+                "ssl requires the client to be built with the `tls` feature".to_string(),
+            )),
+            _ => Ok(builder),
+        }
+    }
+}
+
+fn resolve(address: &str) -> Result<SocketAddr> {
+    let addresses = if address.contains(':') {
+        address.to_socket_addrs()
+    } else {
+        (address, DEFAULT_PORT).to_socket_addrs()
+    };
+    match addresses {
+        Ok(mut addresses) => addresses
+            .next()
+            .ok_or_else(|| InvalidConfiguration(format!("unable to resolve {}", address))),
+        Err(e) => Err(InvalidConfiguration(format!("unable to resolve {} - {}", address, e))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_build_default_config() {
+        let config = ClientConfig::builder().build();
+
+        assert_eq!(config.name(), DEFAULT_NAME);
+        assert_eq!(config.group_name(), DEFAULT_GROUP_NAME);
+        assert_eq!(config.group_password(), DEFAULT_GROUP_PASSWORD);
+        assert_eq!(config.addresses(), [DEFAULT_ADDRESS.parse().unwrap()]);
+        assert_eq!(config.connection_timeout(), DEFAULT_CONNECTION_TIMEOUT);
+        assert_eq!(config.invocation_timeout(), DEFAULT_INVOCATION_TIMEOUT);
+        assert_eq!(config.shutdown_timeout(), DEFAULT_SHUTDOWN_TIMEOUT);
+        assert_eq!(config.frame_size(), DEFAULT_FRAME_SIZE);
+        assert_eq!(config.egress_queue_capacity(), DEFAULT_EGRESS_QUEUE_CAPACITY);
+        assert_eq!(config.max_concurrent_invocations(), DEFAULT_MAX_CONCURRENT_INVOCATIONS);
+        assert!(!config.fail_on_overload());
+        assert_eq!(config.heartbeat_interval(), DEFAULT_HEARTBEAT_INTERVAL);
+        assert_eq!(config.heartbeat_timeout(), DEFAULT_HEARTBEAT_TIMEOUT);
+        assert_eq!(config.retry_policy(), &RetryPolicy::default());
+        assert!(config.smart_routing());
+        assert!(!config.redo_operation());
+        assert!(config.labels().is_empty());
+    }
+
+    #[test]
+    fn should_build_config() {
+        let address: SocketAddr = "10.0.0.1:5701".parse().unwrap();
+        let retry_policy = RetryPolicy::new(Duration::from_millis(10), Duration::from_secs(1), 1.5);
+
+        let config = ClientConfig::builder()
+            .name("some-name")
+            .group_name("some-group")
+            .group_password("some-password")
+            .address(address)
+            .connection_timeout(Duration::from_secs(1))
+            .invocation_timeout(Duration::from_secs(2))
+            .shutdown_timeout(Duration::from_secs(5))
+            .frame_size(1024)
+            .egress_queue_capacity(16)
+            .max_concurrent_invocations(32)
+            .fail_on_overload(true)
+            .heartbeat_interval(Duration::from_secs(3))
+            .heartbeat_timeout(Duration::from_secs(4))
+            .retry_policy(retry_policy.clone())
+            .smart_routing(false)
+            .redo_operation(true)
+            .label("some-label")
+            .build();
+
+        assert_eq!(config.name(), "some-name");
+        assert_eq!(config.group_name(), "some-group");
+        assert_eq!(config.group_password(), "some-password");
+        assert_eq!(config.addresses(), [address]);
+        assert_eq!(config.connection_timeout(), Duration::from_secs(1));
+        assert_eq!(config.invocation_timeout(), Duration::from_secs(2));
+        assert_eq!(config.shutdown_timeout(), Duration::from_secs(5));
+        assert_eq!(config.frame_size(), 1024);
+        assert_eq!(config.egress_queue_capacity(), 16);
+        assert_eq!(config.max_concurrent_invocations(), 32);
+        assert!(config.fail_on_overload());
+        assert_eq!(config.heartbeat_interval(), Duration::from_secs(3));
+        assert_eq!(config.heartbeat_timeout(), Duration::from_secs(4));
+        assert_eq!(config.retry_policy(), &retry_policy);
+        assert!(!config.smart_routing());
+        assert!(config.redo_operation());
+        assert_eq!(config.labels(), ["some-label".to_string()]);
+    }
+
+    #[test]
+    fn should_fail_on_unsupported_file() {
+        assert!(matches!(
+            ClientConfig::from_file("hazelcast-client.xml"),
+            Err(InvalidConfiguration(_))
+        ));
+    }
+
+    #[test]
+    fn should_resolve_address_with_default_port() {
+        assert_eq!(resolve("10.0.0.1").unwrap(), "10.0.0.1:5701".parse().unwrap());
+        assert_eq!(resolve("10.0.0.1:5702").unwrap(), "10.0.0.1:5702".parse().unwrap());
+    }
+
+    #[test]
+    fn should_sanitize_limits() {
+        let config = ClientConfig::builder()
+            .frame_size(0)
+            .heartbeat_interval(Duration::from_secs(0))
+            .egress_queue_capacity(0)
+            .max_concurrent_invocations(0)
+            .build();
+
+        assert_eq!(config.frame_size(), MIN_FRAME_SIZE);
+        assert_eq!(config.heartbeat_interval(), MIN_HEARTBEAT_INTERVAL);
+        assert_eq!(config.egress_queue_capacity(), 1);
+        assert_eq!(config.max_concurrent_invocations(), 1);
+    }
+
+    #[test]
+    fn should_sanitize_retry_policy() {
+        let retry_policy = RetryPolicy::new(Duration::from_secs(2), Duration::from_secs(1), 0.5);
+
+        assert_eq!(retry_policy.max_backoff(), Duration::from_secs(2));
+        assert_eq!(retry_policy.multiplier(), 1.0);
+        assert_eq!(
+            RetryPolicy::new(Duration::from_secs(1), Duration::from_secs(2), f64::NAN).multiplier(),
+            1.0
+        );
+    }
+}
@@ -1,19 +1,48 @@
 #[macro_use]
 extern crate hazelcast_rust_client_macros;
 
-use std::{error, net::SocketAddr, sync::Arc};
+use std::{error, sync::Arc, time::Duration};
 
 use log::info;
 use thiserror::Error;
+use tokio::stream::Stream;
 
-pub use protocol::pn_counter::PnCounter;
+pub use config::{ClientConfig, ClientConfigBuilder, RetryPolicy};
+pub use messaging::{
+    error::{Exception, StackTraceEntry},
+    Address, AttributeEntry, ClusterMember,
+};
+pub use protocol::{
+    map::IMap,
+    pn_counter::PnCounter,
+    queue::{IQueue, ItemEvent, ItemEventType},
+    reliable_topic::{LossPolicy, OverloadPolicy, ReliableMessage, ReliableSubscription, ReliableTopic},
+    topic::{ITopic, Subscription, TopicMessage},
+};
+pub use remote::{
+    channel::WriteMetrics, cluster::ConnectionEvent, lifecycle::LifecycleState, membership::MembershipEvent,
+};
+pub use serialization::{ObjectDataInput, ObjectDataOutput, Serializer};
 
-use crate::remote::cluster::Cluster;
+#[cfg(feature = "tls")]
+pub use config::TlsConfig;
+
+#[cfg(any(test, feature = "test-server"))]
+pub use codec::{DecodeError, Readable, Reader, Writeable, Writer};
+#[cfg(any(test, feature = "test-server"))]
+pub use remote::{
+    test_cluster::TestCluster,
+    test_server::{payload, Reply, TestServer},
+};
+
+use crate::{remote::cluster::Cluster, serialization::SerializationService};
 
 mod codec;
+mod config;
 mod messaging;
 mod protocol;
 mod remote;
+mod serialization;
 
 #[derive(Error, Debug)]
 pub enum HazelcastClientError {
@@ -25,33 +54,194 @@ pub enum HazelcastClientError {
     ClusterNonOperational,
     #[error("unable to communicate with the server ({0})")]
     CommunicationFailure(Box<dyn error::Error + Send + Sync>),
+    #[error("connection to the server has been closed ({0})")]
+    ConnectionClosed(String),
+    #[error("unable to complete invocation in time ({0:?})")]
+    InvocationTimeout(Duration),
+    #[error("caller is not a member of the cluster ({0})")]
+    CallerNotMember(Exception),
+    #[error("unable to cast class ({0})")]
+    ClassCast(Exception),
+    #[error("distributed object has been destroyed ({0})")]
+    DistributedObjectDestroyed(Exception),
+    #[error("cluster member is not active ({0})")]
+    HazelcastInstanceNotActive(Exception),
+    #[error("cluster member is overloaded ({0})")]
+    HazelcastOverload(Exception),
+    #[error("cluster member was unable to (de)serialize value ({0})")]
+    HazelcastSerialization(Exception),
+    #[error("illegal argument ({0})")]
+    IllegalArgument(Exception),
+    #[error("illegal state ({0})")]
+    IllegalState(Exception),
+    #[error("cluster member has left ({0})")]
+    MemberLeft(Exception),
+    #[error("null pointer ({0})")]
+    NullPointer(Exception),
+    #[error("operation timed out ({0})")]
+    Timeout(Exception),
+    #[error("partition is migrating ({0})")]
+    PartitionMigrating(Exception),
+    #[error("operation can be retried ({0})")]
+    Retryable(Exception),
+    #[error("sequence is stale ({0})")]
+    StaleSequence(Exception),
+    #[error("target has disconnected ({0})")]
+    TargetDisconnected(Exception),
+    #[error("target is not a member of the cluster ({0})")]
+    TargetNotMember(Exception),
+    #[error("unsupported operation ({0})")]
+    UnsupportedOperation(Exception),
+    #[error("operation was sent to the wrong target ({0})")]
+    WrongTarget(Exception),
+    #[error("no data member in the cluster ({0})")]
+    NoDataMember(Exception),
+    #[error("target is not a replica ({0})")]
+    TargetNotReplica(Exception),
+    #[error("consistency has been lost ({0})")]
+    ConsistencyLost(Exception),
     #[error("server was unable to process messaging ({0})")]
-    ServerFailure(Box<dyn error::Error + Send + Sync>),
+    ServerFailure(Exception),
+    #[error("unable to (de)serialize value ({0})")]
+    SerializationFailure(String),
+    #[error("client has been shut down")]
+    ClientShutdown,
+    #[error("client is overloaded")]
+    Overloaded,
+    #[error("topic is overloaded ({0})")]
+    TopicOverloaded(String),
+    #[error("invalid configuration ({0})")]
+    InvalidConfiguration(String),
+}
+
+impl HazelcastClientError {
+    pub fn is_retryable(&self) -> bool {
+        use HazelcastClientError::*;
+
+        matches!(
+            self,
+            ClusterNonOperational
+                | CommunicationFailure(_)
+                | ConnectionClosed(_)
+                | CallerNotMember(_)
+                | HazelcastInstanceNotActive(_)
+                | MemberLeft(_)
+                | PartitionMigrating(_)
+                | Retryable(_)
+                | TargetDisconnected(_)
+                | TargetNotMember(_)
+                | WrongTarget(_)
+        )
+    }
+
+    pub(crate) fn is_indeterminate(&self) -> bool {
+        use HazelcastClientError::*;
+
+        matches!(
+            self,
+            CommunicationFailure(_) | ConnectionClosed(_) | HazelcastInstanceNotActive(_) | TargetDisconnected(_)
+        )
+    }
 }
 
 pub struct HazelcastClient {
     cluster: Arc<Cluster>,
+    serialization: SerializationService,
 }
 
 impl HazelcastClient {
-    pub async fn new<E>(endpoints: E, username: &str, password: &str) -> Result<Self>
-    where
-        E: IntoIterator<Item = SocketAddr>,
-    {
-        info!("HazelcastClient {} is STARTING", env!("CARGO_PKG_VERSION"));
-        let cluster = Cluster::init(endpoints, username, password).await?;
+    pub fn builder() -> ClientConfigBuilder {
+        ClientConfig::builder()
+    }
+
+    pub async fn new(config: ClientConfig) -> Result<Self> {
+        let cluster = Cluster::init(&config).await?;
         info!("{}", cluster.to_string().await);
-        info!("HazelcastClient is CONNECTED");
-        info!("HazelcastClient is STARTED");
 
         Ok(HazelcastClient {
             cluster: Arc::new(cluster),
+            serialization: SerializationService::default(),
         })
     }
 
+    pub async fn shutdown(&self) {
+        self.cluster.shutdown().await
+    }
+
+    pub fn lifecycle_state(&self) -> LifecycleState {
+        self.cluster.lifecycle_state()
+    }
+
+    pub fn lifecycle_states(&self) -> impl Stream<Item = LifecycleState> {
+        self.cluster.lifecycle_states()
+    }
+
+    pub async fn add_lifecycle_listener<L>(&self, listener: L) -> u64
+    where
+        L: Fn(&LifecycleState) + Send + Sync + 'static,
+    {
+        self.cluster.add_lifecycle_listener(listener).await
+    }
+
+    pub async fn remove_lifecycle_listener(&self, id: u64) -> bool {
+        self.cluster.remove_lifecycle_listener(id).await
+    }
+
+    pub fn connection_events(&self) -> impl Stream<Item = ConnectionEvent> {
+        self.cluster.connection_events()
+    }
+
+    pub fn write_metrics(&self) -> WriteMetrics {
+        self.cluster.write_metrics()
+    }
+
+    pub async fn members(&self) -> Vec<ClusterMember> {
+        self.cluster.members().await
+    }
+
+    pub async fn add_membership_listener<L>(&self, listener: L) -> u64
+    where
+        L: Fn(&MembershipEvent) + Send + Sync + 'static,
+    {
+        self.cluster.add_membership_listener(listener).await
+    }
+
+    pub async fn remove_membership_listener(&self, id: u64) -> bool {
+        self.cluster.remove_membership_listener(id).await
+    }
+
     pub fn pn_counter(&self, name: &str) -> PnCounter {
         PnCounter::new(name, self.cluster.clone())
     }
+
+    pub fn map<K, V>(&self, name: &str) -> IMap<K, V>
+    where
+        K: Serializer,
+        V: Serializer,
+    {
+        IMap::new(name, self.cluster.clone(), self.serialization.clone())
+    }
+
+    pub fn queue<T>(&self, name: &str) -> IQueue<T>
+    where
+        T: Serializer,
+    {
+        IQueue::new(name, self.cluster.clone(), self.serialization.clone())
+    }
+
+    pub fn topic<T>(&self, name: &str) -> ITopic<T>
+    where
+        T: Serializer,
+    {
+        ITopic::new(name, self.cluster.clone(), self.serialization.clone())
+    }
+
+    pub fn reliable_topic<T>(&self, name: &str) -> ReliableTopic<T>
+    where
+        T: Serializer,
+    {
+        ReliableTopic::new(name, self.cluster.clone(), self.serialization.clone())
+    }
 }
 
 type Result<T> = std::result::Result<T, HazelcastClientError>;
@@ -4,6 +4,7 @@ use crate::messaging::{Address, ClusterMember};
 
 #[derive(Request, Eq, PartialEq, Debug)]
 #[r#type = 0x2]
+#[retryable]
 pub(crate) struct AuthenticationRequest<'a> {
     username: &'a str,
     password: &'a str,
@@ -13,6 +14,8 @@ pub(crate) struct AuthenticationRequest<'a> {
     client_type: &'a str,
     serialization_version: u8,
     client_version: &'a str,
+    client_name: &'a str,
+    labels: &'a [String],
 }
 
 impl<'a> AuthenticationRequest<'a> {
@@ -32,8 +35,31 @@ impl<'a> AuthenticationRequest<'a> {
             client_type,
             serialization_version,
             client_version,
+            client_name: "",
+            labels: &[],
         }
     }
+
+    pub(crate) fn principal(mut self, id: &'a str, owner_id: &'a str) -> Self {
+        self.id = Some(id);
+        self.owner_id = Some(owner_id);
+        self
+    }
+
+    pub(crate) fn client_name(mut self, client_name: &'a str) -> Self {
+        self.client_name = client_name;
+        self
+    }
+
+    pub(crate) fn labels(mut self, labels: &'a [String]) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    pub(crate) fn owner_connection(mut self, owner_connection: bool) -> Self {
+        self.owner_connection = owner_connection;
+        self
+    }
 }
 
 #[derive(Display)]
@@ -42,6 +68,8 @@ pub(crate) enum AuthenticationStatus {
     CredentialsFailed,
     SerializationVersionMismatch,
     NotAllowedInCluster,
+    #[display(fmt = "Unknown({})", _0)]
+    Unknown(u8),
 }
 
 #[derive(Response, Eq, PartialEq, Debug)]
@@ -62,7 +90,7 @@ impl AuthenticationResponse {
             1 => AuthenticationStatus::CredentialsFailed,
             2 => AuthenticationStatus::SerializationVersionMismatch,
             3 => AuthenticationStatus::NotAllowedInCluster,
-            _ => panic!("unknown status - {}", &self.status),
+            status => AuthenticationStatus::Unknown(*status),
         }
     }
 
@@ -81,6 +109,8 @@ impl AuthenticationResponse {
 
 #[cfg(test)]
 mod tests {
+    use std::io::Cursor;
+
     use bytes::{Buf, BytesMut};
 
     use crate::codec::{Reader, Writer};
@@ -94,15 +124,45 @@ mod tests {
         let mut writeable = BytesMut::new();
         request.write_to(&mut writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(String::read_from(readable), request.username);
-        assert_eq!(String::read_from(readable), request.password);
-        assert_eq!(bool::read_from(readable), true);
-        assert_eq!(bool::read_from(readable), true);
-        assert_eq!(bool::read_from(readable), true);
-        assert_eq!(String::read_from(readable), request.client_type);
-        assert_eq!(u8::read_from(readable), request.serialization_version);
-        assert_eq!(String::read_from(readable), request.client_version);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.username);
+        assert_eq!(String::read_from(readable).unwrap(), request.password);
+        assert!(bool::read_from(readable).unwrap());
+        assert!(bool::read_from(readable).unwrap());
+        assert!(bool::read_from(readable).unwrap());
+        assert_eq!(String::read_from(readable).unwrap(), request.client_type);
+        assert_eq!(u8::read_from(readable).unwrap(), request.serialization_version);
+        assert_eq!(String::read_from(readable).unwrap(), request.client_version);
+        assert_eq!(String::read_from(readable).unwrap(), "");
+        assert!(Vec::<String>::read_from(readable).unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_write_authentication_request_with_principal() {
+        let labels = vec!["label".to_string()];
+        let request = AuthenticationRequest::new("username", "password", "Rust", 1, "1.0.0")
+            .principal("id", "owner-id")
+            .owner_connection(false)
+            .client_name("name")
+            .labels(&labels);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.username);
+        assert_eq!(String::read_from(readable).unwrap(), request.password);
+        assert_eq!(Option::<String>::read_from(readable).unwrap().as_deref(), request.id);
+        assert_eq!(
+            Option::<String>::read_from(readable).unwrap().as_deref(),
+            request.owner_id
+        );
+        assert!(!bool::read_from(readable).unwrap());
+        assert_eq!(String::read_from(readable).unwrap(), request.client_type);
+        assert_eq!(u8::read_from(readable).unwrap(), request.serialization_version);
+        assert_eq!(String::read_from(readable).unwrap(), request.client_version);
+        assert_eq!(String::read_from(readable).unwrap(), request.client_name);
+        assert_eq!(Vec::<String>::read_from(readable).unwrap(), labels);
     }
 
     #[test]
@@ -124,9 +184,9 @@ mod tests {
         protocol_version.write_to(writeable);
         true.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
+        let readable = &mut Cursor::new(writeable.to_bytes());
         assert_eq!(
-            AuthenticationResponse::read_from(readable),
+            AuthenticationResponse::read_from(readable).unwrap(),
             AuthenticationResponse {
                 status,
                 address,
@@ -137,4 +197,18 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn should_map_unknown_authentication_status() {
+        let response = AuthenticationResponse {
+            status: 42,
+            address: None,
+            id: None,
+            owner_id: None,
+            _serialization_version: 1,
+            _unregistered_cluster_members: None,
+        };
+
+        assert_eq!(response.status().to_string(), "Unknown(42)");
+    }
 }
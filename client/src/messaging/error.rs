@@ -1,8 +1,34 @@
 use std::{error::Error, fmt};
 
+use crate::HazelcastClientError;
+
+const CALLER_NOT_MEMBER: i32 = 8;
+const CLASS_CAST: i32 = 10;
+const DISTRIBUTED_OBJECT_DESTROYED: i32 = 15;
+const HAZELCAST_INSTANCE_NOT_ACTIVE: i32 = 21;
+const HAZELCAST_OVERLOAD: i32 = 22;
+const HAZELCAST_SERIALIZATION: i32 = 23;
+const ILLEGAL_ARGUMENT: i32 = 25;
+const ILLEGAL_STATE: i32 = 29;
+const MEMBER_LEFT: i32 = 35;
+const NULL_POINTER: i32 = 39;
+const OPERATION_TIMEOUT: i32 = 40;
+const PARTITION_MIGRATING: i32 = 41;
+const RETRYABLE_HAZELCAST: i32 = 49;
+const RETRYABLE_IO: i32 = 50;
+const STALE_SEQUENCE: i32 = 54;
+const TARGET_DISCONNECTED: i32 = 55;
+const TARGET_NOT_MEMBER: i32 = 56;
+const TIMEOUT: i32 = 57;
+const UNSUPPORTED_OPERATION: i32 = 65;
+const WRONG_TARGET: i32 = 66;
+const NO_DATA_MEMBER: i32 = 71;
+const TARGET_NOT_REPLICA: i32 = 86;
+const CONSISTENCY_LOST: i32 = 88;
+
 #[derive(Response, Eq, PartialEq)]
 #[r#type = 0x6D]
-pub(crate) struct Exception {
+pub struct Exception {
     code: i32,
     class_name: String,
     message: Option<String>,
@@ -11,8 +37,65 @@ pub(crate) struct Exception {
     cause_class_name: Option<String>,
 }
 
+impl Exception {
+    pub fn code(&self) -> i32 {
+        self.code
+    }
+
+    pub fn class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    pub fn stack_trace(&self) -> &[StackTraceEntry] {
+        &self.stack_trace
+    }
+
+    pub fn cause_error_code(&self) -> u32 {
+        self.cause_error_code
+    }
+
+    pub fn cause_class_name(&self) -> Option<&str> {
+        self.cause_class_name.as_deref()
+    }
+}
+
 impl Error for Exception {}
 
+impl From<Exception> for HazelcastClientError {
+    fn from(exception: Exception) -> Self {
+        use HazelcastClientError::*;
+
+        match exception.code {
+            CALLER_NOT_MEMBER => CallerNotMember(exception),
+            CLASS_CAST => ClassCast(exception),
+            DISTRIBUTED_OBJECT_DESTROYED => DistributedObjectDestroyed(exception),
+            HAZELCAST_INSTANCE_NOT_ACTIVE => HazelcastInstanceNotActive(exception),
+            HAZELCAST_OVERLOAD => HazelcastOverload(exception),
+            HAZELCAST_SERIALIZATION => HazelcastSerialization(exception),
+            ILLEGAL_ARGUMENT => IllegalArgument(exception),
+            ILLEGAL_STATE => IllegalState(exception),
+            MEMBER_LEFT => MemberLeft(exception),
+            NULL_POINTER => NullPointer(exception),
+            OPERATION_TIMEOUT | TIMEOUT => Timeout(exception),
+            PARTITION_MIGRATING => PartitionMigrating(exception),
+            RETRYABLE_HAZELCAST | RETRYABLE_IO => Retryable(exception),
+            STALE_SEQUENCE => StaleSequence(exception),
+            TARGET_DISCONNECTED => TargetDisconnected(exception),
+            TARGET_NOT_MEMBER => TargetNotMember(exception),
+            UNSUPPORTED_OPERATION => UnsupportedOperation(exception),
+            WRONG_TARGET => WrongTarget(exception),
+            NO_DATA_MEMBER => NoDataMember(exception),
+            TARGET_NOT_REPLICA => TargetNotReplica(exception),
+            CONSISTENCY_LOST => ConsistencyLost(exception),
+            _ => ServerFailure(exception),
+        }
+    }
+}
+
 impl fmt::Display for Exception {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(self, formatter)
@@ -21,32 +104,50 @@ impl fmt::Display for Exception {
 
 impl fmt::Debug for Exception {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
+        writeln!(
             formatter,
-            "Error (code: {}, cause code: {}, cause class name: {:?}) {{\n",
+            "Error (code: {}, cause code: {}, cause class name: {:?}) {{",
             self.code, self.cause_error_code, self.cause_class_name
         )?;
-        write!(
+        writeln!(
             formatter,
-            "\t{}: {}\n",
+            "\t{}: {}",
             self.class_name,
             self.message.as_deref().unwrap_or("")
         )?;
         for stack_trace_entry in &self.stack_trace {
-            write!(formatter, "\t\t{}\n", stack_trace_entry)?;
+            writeln!(formatter, "\t\t{}", stack_trace_entry)?;
         }
         write!(formatter, "}}")
     }
 }
 
 #[derive(Reader, Eq, PartialEq)]
-pub(crate) struct StackTraceEntry {
+pub struct StackTraceEntry {
     declaring_class: String,
     method_name: String,
     file_name: Option<String>,
     line_number: u32,
 }
 
+impl StackTraceEntry {
+    pub fn declaring_class(&self) -> &str {
+        &self.declaring_class
+    }
+
+    pub fn method_name(&self) -> &str {
+        &self.method_name
+    }
+
+    pub fn file_name(&self) -> Option<&str> {
+        self.file_name.as_deref()
+    }
+
+    pub fn line_number(&self) -> u32 {
+        self.line_number
+    }
+}
+
 impl fmt::Display for StackTraceEntry {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(self, formatter)
@@ -68,6 +169,8 @@ impl fmt::Debug for StackTraceEntry {
 
 #[cfg(test)]
 mod tests {
+    use std::io::Cursor;
+
     use bytes::{Buf, BytesMut};
 
     use crate::codec::{Reader, Writer};
@@ -90,9 +193,9 @@ mod tests {
         cause_error_code.write_to(writeable);
         cause_class_name.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
+        let readable = &mut Cursor::new(writeable.to_bytes());
         assert_eq!(
-            Exception::read_from(readable),
+            Exception::read_from(readable).unwrap(),
             Exception {
                 code,
                 class_name: class_name.to_string(),
@@ -104,6 +207,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_convert_exception_to_error() {
+        let error: HazelcastClientError = exception(TARGET_NOT_MEMBER).into();
+        assert!(matches!(error, HazelcastClientError::TargetNotMember(_)));
+
+        let error: HazelcastClientError = exception(TIMEOUT).into();
+        assert!(matches!(error, HazelcastClientError::Timeout(_)));
+
+        let error: HazelcastClientError = exception(RETRYABLE_IO).into();
+        assert!(matches!(error, HazelcastClientError::Retryable(_)));
+
+        let error: HazelcastClientError = exception(CONSISTENCY_LOST).into();
+        assert!(matches!(error, HazelcastClientError::ConsistencyLost(_)));
+    }
+
+    #[test]
+    fn should_convert_unknown_exception_to_server_failure() {
+        match exception(-1).into() {
+            HazelcastClientError::ServerFailure(exception) => {
+                assert_eq!(exception.code(), -1);
+                assert_eq!(exception.class_name(), "SomeException");
+                assert_eq!(exception.message(), Some("message"));
+            }
+            error => panic!("unexpected error: {}", error),
+        }
+    }
+
+    #[test]
+    fn should_classify_retryable_errors() {
+        for code in &[
+            MEMBER_LEFT,
+            TARGET_NOT_MEMBER,
+            PARTITION_MIGRATING,
+            WRONG_TARGET,
+            RETRYABLE_IO,
+        ] {
+            assert!(HazelcastClientError::from(exception(*code)).is_retryable());
+        }
+        for code in &[ILLEGAL_ARGUMENT, STALE_SEQUENCE, TIMEOUT, -1] {
+            assert!(!HazelcastClientError::from(exception(*code)).is_retryable());
+        }
+        assert!(HazelcastClientError::ClusterNonOperational.is_retryable());
+        assert!(HazelcastClientError::ConnectionClosed("cause".to_string()).is_retryable());
+        assert!(!HazelcastClientError::NodeNonOperational.is_retryable());
+
+        for code in &[HAZELCAST_INSTANCE_NOT_ACTIVE, TARGET_DISCONNECTED] {
+            assert!(HazelcastClientError::from(exception(*code)).is_indeterminate());
+        }
+        assert!(HazelcastClientError::ConnectionClosed("cause".to_string()).is_indeterminate());
+        assert!(!HazelcastClientError::from(exception(WRONG_TARGET)).is_indeterminate());
+    }
+
     #[test]
     fn should_read_stack_trace_entry() {
         let declaring_class = "NullPointerException";
@@ -117,9 +272,9 @@ mod tests {
         file_name.write_to(writeable);
         line_number.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
+        let readable = &mut Cursor::new(writeable.to_bytes());
         assert_eq!(
-            StackTraceEntry::read_from(readable),
+            StackTraceEntry::read_from(readable).unwrap(),
             StackTraceEntry {
                 declaring_class: declaring_class.to_string(),
                 method_name: method_name.to_string(),
@@ -128,4 +283,15 @@ mod tests {
             }
         );
     }
+
+    fn exception(code: i32) -> Exception {
+        Exception {
+            code,
+            class_name: "SomeException".to_string(),
+            message: Some("message".to_string()),
+            stack_trace: vec![],
+            cause_error_code: 0,
+            cause_class_name: None,
+        }
+    }
 }
@@ -0,0 +1,355 @@
+use crate::serialization::Data;
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0101]
+pub(crate) struct MapPutRequest<'a> {
+    name: &'a str,
+    key: &'a Data,
+    value: &'a Data,
+    thread_id: i64,
+    ttl: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MapPutRequest<'a> {
+    pub(crate) fn new(name: &'a str, key: &'a Data, value: &'a Data, thread_id: i64, partition_id: i32) -> Self {
+        MapPutRequest {
+            name,
+            key,
+            value,
+            thread_id,
+            ttl: -1,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct MapPutResponse {
+    value: Option<Data>,
+}
+
+impl MapPutResponse {
+    pub(crate) fn value(&self) -> Option<&Data> {
+        self.value.as_ref()
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0102]
+#[retryable]
+pub(crate) struct MapGetRequest<'a> {
+    name: &'a str,
+    key: &'a Data,
+    thread_id: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MapGetRequest<'a> {
+    pub(crate) fn new(name: &'a str, key: &'a Data, thread_id: i64, partition_id: i32) -> Self {
+        MapGetRequest {
+            name,
+            key,
+            thread_id,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct MapGetResponse {
+    value: Option<Data>,
+}
+
+impl MapGetResponse {
+    pub(crate) fn value(&self) -> Option<&Data> {
+        self.value.as_ref()
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0103]
+pub(crate) struct MapRemoveRequest<'a> {
+    name: &'a str,
+    key: &'a Data,
+    thread_id: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MapRemoveRequest<'a> {
+    pub(crate) fn new(name: &'a str, key: &'a Data, thread_id: i64, partition_id: i32) -> Self {
+        MapRemoveRequest {
+            name,
+            key,
+            thread_id,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct MapRemoveResponse {
+    value: Option<Data>,
+}
+
+impl MapRemoveResponse {
+    pub(crate) fn value(&self) -> Option<&Data> {
+        self.value.as_ref()
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0109]
+#[retryable]
+pub(crate) struct MapContainsKeyRequest<'a> {
+    name: &'a str,
+    key: &'a Data,
+    thread_id: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MapContainsKeyRequest<'a> {
+    pub(crate) fn new(name: &'a str, key: &'a Data, thread_id: i64, partition_id: i32) -> Self {
+        MapContainsKeyRequest {
+            name,
+            key,
+            thread_id,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct MapContainsKeyResponse {
+    contains: bool,
+}
+
+impl MapContainsKeyResponse {
+    pub(crate) fn contains(&self) -> bool {
+        self.contains
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x012E]
+#[retryable]
+pub(crate) struct MapSizeRequest<'a> {
+    name: &'a str,
+}
+
+impl<'a> MapSizeRequest<'a> {
+    pub(crate) fn new(name: &'a str) -> Self {
+        MapSizeRequest { name }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x66]
+pub(crate) struct MapSizeResponse {
+    size: u32,
+}
+
+impl MapSizeResponse {
+    pub(crate) fn size(&self) -> u32 {
+        self.size
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0131]
+pub(crate) struct MapClearRequest<'a> {
+    name: &'a str,
+}
+
+impl<'a> MapClearRequest<'a> {
+    pub(crate) fn new(name: &'a str) -> Self {
+        MapClearRequest { name }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x64]
+pub(crate) struct MapClearResponse {}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Request,
+        serialization::SerializationService,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_put_request() {
+        let key = data("key");
+        let value = data("value");
+        let request = MapPutRequest::new("map-name", &key, &value, 1, 13);
+        assert_eq!(request.partition_id(), 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Data::read_from(readable).unwrap(), key);
+        assert_eq!(Data::read_from(readable).unwrap(), value);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(i64::read_from(readable).unwrap(), -1);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_put_response() {
+        let value = Some(data("value"));
+
+        let writeable = &mut BytesMut::new();
+        value.write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(MapPutResponse::read_from(readable).unwrap(), MapPutResponse { value });
+    }
+
+    #[test]
+    fn should_write_get_request() {
+        let key = data("key");
+        let request = MapGetRequest::new("map-name", &key, 1, 13);
+        assert_eq!(request.partition_id(), 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Data::read_from(readable).unwrap(), key);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_get_response() {
+        let writeable = &mut BytesMut::new();
+        Option::<Data>::None.write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(
+            MapGetResponse::read_from(readable).unwrap(),
+            MapGetResponse { value: None }
+        );
+    }
+
+    #[test]
+    fn should_write_remove_request() {
+        let key = data("key");
+        let request = MapRemoveRequest::new("map-name", &key, 1, 13);
+        assert_eq!(request.partition_id(), 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Data::read_from(readable).unwrap(), key);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_remove_response() {
+        let value = Some(data("value"));
+
+        let writeable = &mut BytesMut::new();
+        value.write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(
+            MapRemoveResponse::read_from(readable).unwrap(),
+            MapRemoveResponse { value }
+        );
+    }
+
+    #[test]
+    fn should_write_contains_key_request() {
+        let key = data("key");
+        let request = MapContainsKeyRequest::new("map-name", &key, 1, 13);
+        assert_eq!(request.partition_id(), 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Data::read_from(readable).unwrap(), key);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_contains_key_response() {
+        let writeable = &mut BytesMut::new();
+        true.write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(
+            MapContainsKeyResponse::read_from(readable).unwrap(),
+            MapContainsKeyResponse { contains: true }
+        );
+    }
+
+    #[test]
+    fn should_write_size_request() {
+        let request = MapSizeRequest::new("map-name");
+        assert_eq!(request.partition_id(), -1);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+    }
+
+    #[test]
+    fn should_read_size_response() {
+        let size = 3u32;
+
+        let writeable = &mut BytesMut::new();
+        size.write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(MapSizeResponse::read_from(readable).unwrap(), MapSizeResponse { size });
+    }
+
+    #[test]
+    fn should_write_clear_request() {
+        let request = MapClearRequest::new("map-name");
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+    }
+
+    #[test]
+    fn should_read_clear_response() {
+        let readable = &mut Cursor::new(BytesMut::new().to_bytes());
+        assert_eq!(MapClearResponse::read_from(readable).unwrap(), MapClearResponse {});
+    }
+
+    fn data(value: &str) -> Data {
+        SerializationService::default().to_data(&value.to_string())
+    }
+}
@@ -0,0 +1,180 @@
+use crate::messaging::ClusterMember;
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x4]
+pub(crate) struct AddMembershipListenerRequest {
+    local_only: bool,
+}
+
+impl AddMembershipListenerRequest {
+    pub(crate) fn new(local_only: bool) -> Self {
+        AddMembershipListenerRequest { local_only }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x68]
+pub(crate) struct AddMembershipListenerResponse {
+    id: String,
+}
+
+impl AddMembershipListenerResponse {
+    pub(crate) fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+pub(crate) const MEMBER_ADDED: i32 = 1;
+pub(crate) const MEMBER_REMOVED: i32 = 2;
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0xC8]
+pub(crate) struct MemberEvent {
+    member: ClusterMember,
+    event_type: i32,
+}
+
+impl MemberEvent {
+    pub(crate) fn member(&self) -> &ClusterMember {
+        &self.member
+    }
+
+    pub(crate) fn event_type(&self) -> i32 {
+        self.event_type
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0xC9]
+pub(crate) struct MemberListEvent {
+    members: Vec<ClusterMember>,
+}
+
+impl MemberListEvent {
+    pub(crate) fn members(&self) -> &[ClusterMember] {
+        &self.members
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0xCA]
+pub(crate) struct MemberAttributeChangeEvent {
+    id: String,
+    key: String,
+    _operation_type: i32,
+    value: Option<String>,
+}
+
+impl MemberAttributeChangeEvent {
+    pub(crate) fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub(crate) fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub(crate) fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Address,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_add_membership_listener_request() {
+        let request = AddMembershipListenerRequest::new(true);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert!(bool::read_from(readable).unwrap());
+    }
+
+    #[test]
+    fn should_read_add_membership_listener_response() {
+        let id = "id";
+
+        let writeable = &mut BytesMut::new();
+        id.write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(
+            AddMembershipListenerResponse::read_from(readable).unwrap(),
+            AddMembershipListenerResponse { id: id.to_string() }
+        );
+    }
+
+    #[test]
+    fn should_read_member_event() {
+        let writeable = &mut BytesMut::new();
+        write_member(writeable);
+        MEMBER_REMOVED.write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        let event = MemberEvent::read_from(readable).unwrap();
+        assert_eq!(event.member().id(), "member-id");
+        assert_eq!(event.event_type(), MEMBER_REMOVED);
+    }
+
+    #[test]
+    fn should_read_member_list_event() {
+        let writeable = &mut BytesMut::new();
+        1u32.write_to(writeable);
+        write_member(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        let event = MemberListEvent::read_from(readable).unwrap();
+        assert_eq!(event.members().len(), 1);
+        assert_eq!(event.members()[0].id(), "member-id");
+    }
+
+    #[test]
+    fn should_read_member_attribute_change_event() {
+        let id = "member-id";
+        let key = "key";
+        let operation_type = 1;
+        let value = Some("value");
+
+        let writeable = &mut BytesMut::new();
+        id.write_to(writeable);
+        key.write_to(writeable);
+        operation_type.write_to(writeable);
+        value.write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(
+            MemberAttributeChangeEvent::read_from(readable).unwrap(),
+            MemberAttributeChangeEvent {
+                id: id.to_string(),
+                key: key.to_string(),
+                _operation_type: operation_type,
+                value: value.map(str::to_string),
+            }
+        );
+    }
+
+    fn write_member(writeable: &mut BytesMut) {
+        let address = Address {
+            host: "localhost".to_string(),
+            port: 5701,
+        };
+
+        address.write_to(writeable);
+        "member-id".write_to(writeable);
+        false.write_to(writeable);
+        0u32.write_to(writeable);
+    }
+}
@@ -6,8 +6,14 @@ use crate::codec::{Reader, Writer};
 
 pub(crate) mod authentication;
 pub(crate) mod error;
+pub(crate) mod map;
+pub(crate) mod membership;
+pub(crate) mod partition;
 pub(crate) mod ping;
 pub(crate) mod pn_counter;
+pub(crate) mod queue;
+pub(crate) mod ringbuffer;
+pub(crate) mod topic;
 
 pub(crate) trait Request: Writer {
     fn r#type() -> u16;
@@ -15,6 +21,10 @@ pub(crate) trait Request: Writer {
     fn partition_id(&self) -> i32 {
         -1
     }
+
+    fn is_retryable() -> bool {
+        false
+    }
 }
 
 pub(crate) trait Response: Reader {
@@ -23,11 +33,28 @@ pub(crate) trait Response: Reader {
 
 #[derive(Writer, Reader, Eq, PartialEq, Hash, Display, Debug, Clone)]
 #[display(fmt = "{}:{}", host, port)]
-pub(crate) struct Address {
+pub struct Address {
     host: String,
     port: u32,
 }
 
+impl Address {
+    pub(crate) fn new(host: &str, port: u32) -> Self {
+        Address {
+            host: host.to_string(),
+            port,
+        }
+    }
+
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    pub fn port(&self) -> u32 {
+        self.port
+    }
+}
+
 impl From<&std::net::SocketAddr> for Address {
     fn from(address: &SocketAddr) -> Self {
         Address {
@@ -37,18 +64,64 @@ impl From<&std::net::SocketAddr> for Address {
     }
 }
 
-#[derive(Reader, Eq, PartialEq, Debug)]
-pub(crate) struct ClusterMember {
+#[derive(Reader, Eq, PartialEq, Display, Debug, Clone)]
+#[display(fmt = "{} - {}", address, id)]
+pub struct ClusterMember {
     address: Address,
     id: String,
     lite: bool,
     attributes: Vec<AttributeEntry>,
 }
 
+impl ClusterMember {
+    pub fn address(&self) -> &Address {
+        &self.address
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn is_lite(&self) -> bool {
+        self.lite
+    }
+
+    pub fn attributes(&self) -> &[AttributeEntry] {
+        &self.attributes
+    }
+
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.as_str())
+    }
+
+    pub(crate) fn set_attribute(&mut self, key: &str, value: Option<&str>) {
+        self.attributes.retain(|attribute| attribute.key != key);
+        if let Some(value) = value {
+            self.attributes.push(AttributeEntry {
+                key: key.to_string(),
+                value: value.to_string(),
+            });
+        }
+    }
+}
+
 #[derive(Reader, Eq, PartialEq, Debug, Clone)]
-pub(crate) struct AttributeEntry {
-    _key: String,
-    _value: String,
+pub struct AttributeEntry {
+    key: String,
+    value: String,
+}
+
+impl AttributeEntry {
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
 }
 
 #[derive(Writer, Reader, Eq, PartialEq, Debug, Clone)]
@@ -59,6 +132,8 @@ pub(crate) struct ReplicaTimestampEntry {
 
 #[cfg(test)]
 mod tests {
+    use std::io::Cursor;
+
     use bytes::{Buf, BytesMut};
 
     use crate::codec::{Reader, Writer};
@@ -75,8 +150,8 @@ mod tests {
         let mut writeable = BytesMut::new();
         address.write_to(&mut writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(Address::read_from(readable), address);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(Address::read_from(readable).unwrap(), address);
     }
 
     #[test]
@@ -94,9 +169,9 @@ mod tests {
         lite.write_to(writeable);
         0u32.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
+        let readable = &mut Cursor::new(writeable.to_bytes());
         assert_eq!(
-            ClusterMember::read_from(readable),
+            ClusterMember::read_from(readable).unwrap(),
             ClusterMember {
                 address,
                 id: id.to_string(),
@@ -115,16 +190,39 @@ mod tests {
         key.write_to(writeable);
         value.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
+        let readable = &mut Cursor::new(writeable.to_bytes());
         assert_eq!(
-            AttributeEntry::read_from(readable),
+            AttributeEntry::read_from(readable).unwrap(),
             AttributeEntry {
-                _key: key.to_string(),
-                _value: value.to_string(),
+                key: key.to_string(),
+                value: value.to_string(),
             }
         );
     }
 
+    #[test]
+    fn should_set_and_remove_attribute() {
+        let mut member = ClusterMember {
+            address: Address {
+                host: "localhost".to_string(),
+                port: 5701,
+            },
+            id: "id".to_string(),
+            lite: false,
+            attributes: vec![],
+        };
+
+        member.set_attribute("key", Some("value"));
+        assert_eq!(member.attribute("key"), Some("value"));
+
+        member.set_attribute("key", Some("other-value"));
+        assert_eq!(member.attribute("key"), Some("other-value"));
+        assert_eq!(member.attributes().len(), 1);
+
+        member.set_attribute("key", None);
+        assert_eq!(member.attribute("key"), None);
+    }
+
     #[test]
     fn should_write_replica_timestamp_entry() {
         let replica_timestamp = ReplicaTimestampEntry {
@@ -135,9 +233,9 @@ mod tests {
         let writeable = &mut BytesMut::new();
         replica_timestamp.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(String::read_from(readable), replica_timestamp.key);
-        assert_eq!(i64::read_from(readable), replica_timestamp.value);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), replica_timestamp.key);
+        assert_eq!(i64::read_from(readable).unwrap(), replica_timestamp.value);
     }
 
     #[test]
@@ -149,9 +247,9 @@ mod tests {
         key.write_to(writeable);
         value.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
+        let readable = &mut Cursor::new(writeable.to_bytes());
         assert_eq!(
-            ReplicaTimestampEntry::read_from(readable),
+            ReplicaTimestampEntry::read_from(readable).unwrap(),
             ReplicaTimestampEntry {
                 key: key.to_string(),
                 value,
@@ -0,0 +1,88 @@
+use crate::messaging::Address;
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x8]
+#[retryable]
+pub(crate) struct GetPartitionsRequest {}
+
+impl GetPartitionsRequest {
+    pub(crate) fn new() -> Self {
+        GetPartitionsRequest {}
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x6C]
+pub(crate) struct GetPartitionsResponse {
+    partitions: Vec<PartitionsEntry>,
+    _partition_state_version: u32,
+}
+
+impl GetPartitionsResponse {
+    pub(crate) fn partitions(&self) -> &[PartitionsEntry] {
+        &self.partitions
+    }
+}
+
+#[derive(Reader, Eq, PartialEq, Debug)]
+pub(crate) struct PartitionsEntry {
+    address: Address,
+    ids: Vec<u32>,
+}
+
+impl PartitionsEntry {
+    pub(crate) fn address(&self) -> &Address {
+        &self.address
+    }
+
+    pub(crate) fn ids(&self) -> &[u32] {
+        &self.ids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, ops::Deref};
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::codec::{Reader, Writer};
+
+    use super::*;
+
+    #[test]
+    fn should_write_get_partitions_request() {
+        let request = GetPartitionsRequest::new();
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert!(readable.bytes().is_empty());
+    }
+
+    #[test]
+    fn should_read_get_partitions_response() {
+        let address = Address {
+            host: "localhost".to_string(),
+            port: 5701,
+        };
+        let ids = vec![0u32, 1, 2];
+        let partition_state_version = 7u32;
+
+        let writeable = &mut BytesMut::new();
+        1u32.write_to(writeable);
+        address.write_to(writeable);
+        ids.deref().write_to(writeable);
+        partition_state_version.write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(
+            GetPartitionsResponse::read_from(readable).unwrap(),
+            GetPartitionsResponse {
+                partitions: vec![PartitionsEntry { address, ids }],
+                _partition_state_version: partition_state_version,
+            }
+        );
+    }
+}
@@ -1,5 +1,6 @@
 #[derive(Request, Eq, PartialEq, Debug)]
 #[r#type = 0xf]
+#[retryable]
 pub(crate) struct PingRequest {}
 
 impl PingRequest {
@@ -14,6 +15,8 @@ pub(crate) struct PingResponse {}
 
 #[cfg(test)]
 mod tests {
+    use std::io::Cursor;
+
     use bytes::{Buf, BytesMut};
 
     use crate::codec::{Reader, Writer};
@@ -27,13 +30,13 @@ mod tests {
         let mut writeable = BytesMut::new();
         request.write_to(&mut writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(readable.bytes(), []);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert!(readable.bytes().is_empty());
     }
 
     #[test]
     fn should_read_ping_response() {
-        let readable = &mut BytesMut::new().to_bytes();
-        assert_eq!(PingResponse::read_from(readable), PingResponse {});
+        let readable = &mut Cursor::new(BytesMut::new().to_bytes());
+        assert_eq!(PingResponse::read_from(readable).unwrap(), PingResponse {});
     }
 }
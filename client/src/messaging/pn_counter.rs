@@ -2,6 +2,7 @@ use crate::messaging::{Address, ReplicaTimestampEntry};
 
 #[derive(Request, Eq, PartialEq, Debug)]
 #[r#type = 0x2001]
+#[retryable]
 pub(crate) struct PnCounterGetRequest<'a> {
     name: &'a str,
     replica_timestamps: &'a [ReplicaTimestampEntry],
@@ -83,6 +84,7 @@ impl PnCounterAddResponse {
 
 #[derive(Request, Eq, PartialEq, Debug)]
 #[r#type = 0x2003]
+#[retryable]
 pub(crate) struct PnCounterGetReplicaCountRequest<'a> {
     name: &'a str,
 }
@@ -107,7 +109,7 @@ impl PnCounterGetReplicaCountResponse {
 
 #[cfg(test)]
 mod tests {
-    use std::ops::Deref;
+    use std::{io::Cursor, ops::Deref};
 
     use bytes::{Buf, BytesMut};
 
@@ -130,13 +132,13 @@ mod tests {
         let mut writeable = BytesMut::new();
         request.write_to(&mut writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(String::read_from(readable), request.name);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
         assert_eq!(
-            Vec::<ReplicaTimestampEntry>::read_from(readable).deref(),
+            Vec::<ReplicaTimestampEntry>::read_from(readable).unwrap().deref(),
             replica_timestamps
         );
-        assert_eq!(&Address::read_from(readable), request.address);
+        assert_eq!(&Address::read_from(readable).unwrap(), request.address);
     }
 
     #[test]
@@ -151,9 +153,9 @@ mod tests {
         value.write_to(writeable);
         replica_timestamps.deref().write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
+        let readable = &mut Cursor::new(writeable.to_bytes());
         assert_eq!(
-            PnCounterGetResponse::read_from(readable),
+            PnCounterGetResponse::read_from(readable).unwrap(),
             PnCounterGetResponse {
                 value,
                 replica_timestamps,
@@ -176,15 +178,15 @@ mod tests {
         let mut writeable = BytesMut::new();
         request.write_to(&mut writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(String::read_from(readable), request.name);
-        assert_eq!(i64::read_from(readable), request.delta);
-        assert_eq!(bool::read_from(readable), request.get_before_update);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i64::read_from(readable).unwrap(), request.delta);
+        assert_eq!(bool::read_from(readable).unwrap(), request.get_before_update);
         assert_eq!(
-            Vec::<ReplicaTimestampEntry>::read_from(readable).deref(),
+            Vec::<ReplicaTimestampEntry>::read_from(readable).unwrap().deref(),
             replica_timestamps
         );
-        assert_eq!(&Address::read_from(readable), request.address);
+        assert_eq!(&Address::read_from(readable).unwrap(), request.address);
     }
 
     #[test]
@@ -201,9 +203,9 @@ mod tests {
         replica_timestamps.deref().write_to(writeable);
         replica_count.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
+        let readable = &mut Cursor::new(writeable.to_bytes());
         assert_eq!(
-            PnCounterAddResponse::read_from(readable),
+            PnCounterAddResponse::read_from(readable).unwrap(),
             PnCounterAddResponse {
                 value,
                 replica_timestamps,
@@ -219,8 +221,8 @@ mod tests {
         let mut writeable = BytesMut::new();
         request.write_to(&mut writeable);
 
-        let readable = &mut writeable.to_bytes();
-        assert_eq!(String::read_from(readable), request.name);
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
     }
 
     #[test]
@@ -230,9 +232,9 @@ mod tests {
         let writeable = &mut BytesMut::new();
         count.write_to(writeable);
 
-        let readable = &mut writeable.to_bytes();
+        let readable = &mut Cursor::new(writeable.to_bytes());
         assert_eq!(
-            PnCounterGetReplicaCountResponse::read_from(readable),
+            PnCounterGetReplicaCountResponse::read_from(readable).unwrap(),
             PnCounterGetReplicaCountResponse { count }
         );
     }
@@ -0,0 +1,400 @@
+use crate::serialization::Data;
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0301]
+pub(crate) struct QueueOfferRequest<'a> {
+    name: &'a str,
+    value: &'a Data,
+    timeout: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> QueueOfferRequest<'a> {
+    pub(crate) fn new(name: &'a str, value: &'a Data, timeout: i64, partition_id: i32) -> Self {
+        QueueOfferRequest {
+            name,
+            value,
+            timeout,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct QueueOfferResponse {
+    offered: bool,
+}
+
+impl QueueOfferResponse {
+    pub(crate) fn offered(&self) -> bool {
+        self.offered
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0303]
+#[retryable]
+pub(crate) struct QueueSizeRequest<'a> {
+    name: &'a str,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> QueueSizeRequest<'a> {
+    pub(crate) fn new(name: &'a str, partition_id: i32) -> Self {
+        QueueSizeRequest { name, partition_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x66]
+pub(crate) struct QueueSizeResponse {
+    size: u32,
+}
+
+impl QueueSizeResponse {
+    pub(crate) fn size(&self) -> u32 {
+        self.size
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0305]
+pub(crate) struct QueuePollRequest<'a> {
+    name: &'a str,
+    timeout: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> QueuePollRequest<'a> {
+    pub(crate) fn new(name: &'a str, timeout: i64, partition_id: i32) -> Self {
+        QueuePollRequest {
+            name,
+            timeout,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct QueuePollResponse {
+    value: Option<Data>,
+}
+
+impl QueuePollResponse {
+    pub(crate) fn value(&self) -> Option<&Data> {
+        self.value.as_ref()
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0306]
+pub(crate) struct QueueTakeRequest<'a> {
+    name: &'a str,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> QueueTakeRequest<'a> {
+    pub(crate) fn new(name: &'a str, partition_id: i32) -> Self {
+        QueueTakeRequest { name, partition_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct QueueTakeResponse {
+    value: Option<Data>,
+}
+
+impl QueueTakeResponse {
+    pub(crate) fn value(&self) -> Option<&Data> {
+        self.value.as_ref()
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0307]
+#[retryable]
+pub(crate) struct QueuePeekRequest<'a> {
+    name: &'a str,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> QueuePeekRequest<'a> {
+    pub(crate) fn new(name: &'a str, partition_id: i32) -> Self {
+        QueuePeekRequest { name, partition_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct QueuePeekResponse {
+    value: Option<Data>,
+}
+
+impl QueuePeekResponse {
+    pub(crate) fn value(&self) -> Option<&Data> {
+        self.value.as_ref()
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x030A]
+pub(crate) struct QueueDrainToMaxSizeRequest<'a> {
+    name: &'a str,
+    max_size: i32,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> QueueDrainToMaxSizeRequest<'a> {
+    pub(crate) fn new(name: &'a str, max_size: i32, partition_id: i32) -> Self {
+        QueueDrainToMaxSizeRequest {
+            name,
+            max_size,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x6A]
+pub(crate) struct QueueDrainToMaxSizeResponse {
+    values: Vec<Data>,
+}
+
+impl QueueDrainToMaxSizeResponse {
+    pub(crate) fn values(&self) -> &[Data] {
+        &self.values
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0311]
+pub(crate) struct QueueAddListenerRequest {
+    name: String,
+    include_value: bool,
+    local_only: bool,
+}
+
+impl QueueAddListenerRequest {
+    pub(crate) fn new(name: &str, include_value: bool, local_only: bool) -> Self {
+        QueueAddListenerRequest {
+            name: name.to_string(),
+            include_value,
+            local_only,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x68]
+pub(crate) struct QueueAddListenerResponse {
+    id: String,
+}
+
+impl QueueAddListenerResponse {
+    pub(crate) fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0312]
+#[retryable]
+pub(crate) struct QueueRemoveListenerRequest {
+    name: String,
+    id: String,
+}
+
+impl QueueRemoveListenerRequest {
+    pub(crate) fn new(name: &str, id: &str) -> Self {
+        QueueRemoveListenerRequest {
+            name: name.to_string(),
+            id: id.to_string(),
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct QueueRemoveListenerResponse {
+    removed: bool,
+}
+
+impl QueueRemoveListenerResponse {
+    pub(crate) fn removed(&self) -> bool {
+        self.removed
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0313]
+#[retryable]
+pub(crate) struct QueueRemainingCapacityRequest<'a> {
+    name: &'a str,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> QueueRemainingCapacityRequest<'a> {
+    pub(crate) fn new(name: &'a str, partition_id: i32) -> Self {
+        QueueRemainingCapacityRequest { name, partition_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x66]
+pub(crate) struct QueueRemainingCapacityResponse {
+    capacity: u32,
+}
+
+impl QueueRemainingCapacityResponse {
+    pub(crate) fn capacity(&self) -> u32 {
+        self.capacity
+    }
+}
+
+pub(crate) const ITEM_ADDED: i32 = 1;
+pub(crate) const ITEM_REMOVED: i32 = 2;
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0xCC]
+pub(crate) struct ItemEvent {
+    item: Option<Data>,
+    member_id: String,
+    event_type: i32,
+}
+
+impl ItemEvent {
+    pub(crate) fn item(&self) -> Option<&Data> {
+        self.item.as_ref()
+    }
+
+    pub(crate) fn member_id(&self) -> &str {
+        &self.member_id
+    }
+
+    pub(crate) fn event_type(&self) -> i32 {
+        self.event_type
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Request,
+        serialization::SerializationService,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_offer_request() {
+        let value = data("value");
+        let request = QueueOfferRequest::new("queue-name", &value, 1000, 13);
+        assert_eq!(request.partition_id(), 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Data::read_from(readable).unwrap(), value);
+        assert_eq!(i64::read_from(readable).unwrap(), request.timeout);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_write_poll_request() {
+        let request = QueuePollRequest::new("queue-name", 1000, 13);
+        assert_eq!(request.partition_id(), 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i64::read_from(readable).unwrap(), request.timeout);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_poll_response() {
+        let value = Some(data("value"));
+
+        let writeable = &mut BytesMut::new();
+        value.write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(
+            QueuePollResponse::read_from(readable).unwrap(),
+            QueuePollResponse { value }
+        );
+    }
+
+    #[test]
+    fn should_read_drain_to_max_size_response() {
+        let values = vec![data("value"), data("other-value")];
+
+        let writeable = &mut BytesMut::new();
+        values.as_slice().write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(
+            QueueDrainToMaxSizeResponse::read_from(readable).unwrap(),
+            QueueDrainToMaxSizeResponse { values }
+        );
+    }
+
+    #[test]
+    fn should_write_add_listener_request() {
+        let request = QueueAddListenerRequest::new("queue-name", true, false);
+        assert_eq!(request.partition_id(), -1);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(bool::read_from(readable).unwrap(), request.include_value);
+        assert_eq!(bool::read_from(readable).unwrap(), request.local_only);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_item_event() {
+        let item = Some(data("value"));
+        let member_id = "member-id".to_string();
+
+        let writeable = &mut BytesMut::new();
+        item.write_to(writeable);
+        member_id.write_to(writeable);
+        ITEM_ADDED.write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(
+            ItemEvent::read_from(readable).unwrap(),
+            ItemEvent {
+                item,
+                member_id,
+                event_type: ITEM_ADDED,
+            }
+        );
+    }
+
+    fn data(value: &str) -> Data {
+        SerializationService::default().to_data(&value.to_string())
+    }
+}
@@ -0,0 +1,210 @@
+use crate::serialization::Data;
+
+pub(crate) const OVERWRITE: i32 = 0;
+pub(crate) const FAIL: i32 = 1;
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x1902]
+#[retryable]
+pub(crate) struct RingbufferTailSequenceRequest<'a> {
+    name: &'a str,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> RingbufferTailSequenceRequest<'a> {
+    pub(crate) fn new(name: &'a str, partition_id: i32) -> Self {
+        RingbufferTailSequenceRequest { name, partition_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x67]
+pub(crate) struct RingbufferTailSequenceResponse {
+    sequence: i64,
+}
+
+impl RingbufferTailSequenceResponse {
+    pub(crate) fn sequence(&self) -> i64 {
+        self.sequence
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x1903]
+#[retryable]
+pub(crate) struct RingbufferHeadSequenceRequest<'a> {
+    name: &'a str,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> RingbufferHeadSequenceRequest<'a> {
+    pub(crate) fn new(name: &'a str, partition_id: i32) -> Self {
+        RingbufferHeadSequenceRequest { name, partition_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x67]
+pub(crate) struct RingbufferHeadSequenceResponse {
+    sequence: i64,
+}
+
+impl RingbufferHeadSequenceResponse {
+    pub(crate) fn sequence(&self) -> i64 {
+        self.sequence
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x1906]
+pub(crate) struct RingbufferAddRequest<'a> {
+    name: &'a str,
+    overflow_policy: i32,
+    value: &'a Data,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> RingbufferAddRequest<'a> {
+    pub(crate) fn new(name: &'a str, overflow_policy: i32, value: &'a Data, partition_id: i32) -> Self {
+        RingbufferAddRequest {
+            name,
+            overflow_policy,
+            value,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x67]
+pub(crate) struct RingbufferAddResponse {
+    sequence: i64,
+}
+
+impl RingbufferAddResponse {
+    pub(crate) fn sequence(&self) -> i64 {
+        self.sequence
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x1909]
+#[retryable]
+pub(crate) struct RingbufferReadManyRequest<'a> {
+    name: &'a str,
+    start_sequence: i64,
+    min_count: i32,
+    max_count: i32,
+    filter: Option<Data>,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> RingbufferReadManyRequest<'a> {
+    pub(crate) fn new(name: &'a str, start_sequence: i64, min_count: i32, max_count: i32, partition_id: i32) -> Self {
+        RingbufferReadManyRequest {
+            name,
+            start_sequence,
+            min_count,
+            max_count,
+            filter: None,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x73]
+pub(crate) struct RingbufferReadManyResponse {
+    _read_count: i32,
+    items: Vec<Data>,
+    item_sequences: Option<Vec<i64>>,
+    next_sequence: i64,
+}
+
+impl RingbufferReadManyResponse {
+    pub(crate) fn items(&self) -> &[Data] {
+        &self.items
+    }
+
+    pub(crate) fn item_sequences(&self) -> Option<&[i64]> {
+        self.item_sequences.as_deref()
+    }
+
+    pub(crate) fn next_sequence(&self) -> i64 {
+        self.next_sequence
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Request,
+        serialization::SerializationService,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_add_request() {
+        let value = SerializationService::default().to_data(&"value".to_string());
+        let request = RingbufferAddRequest::new("ringbuffer-name", OVERWRITE, &value, 13);
+        assert_eq!(request.partition_id(), 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i32::read_from(readable).unwrap(), OVERWRITE);
+        assert_eq!(Data::read_from(readable).unwrap(), value);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_write_read_many_request() {
+        let request = RingbufferReadManyRequest::new("ringbuffer-name", 7, 1, 10, 13);
+        assert_eq!(request.partition_id(), 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i64::read_from(readable).unwrap(), request.start_sequence);
+        assert_eq!(i32::read_from(readable).unwrap(), request.min_count);
+        assert_eq!(i32::read_from(readable).unwrap(), request.max_count);
+        assert_eq!(Option::<Data>::read_from(readable).unwrap(), None);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_read_many_response() {
+        let items = vec![SerializationService::default().to_data(&"value".to_string())];
+
+        let writeable = &mut BytesMut::new();
+        1i32.write_to(writeable);
+        items.as_slice().write_to(writeable);
+        Some([7i64].as_ref()).write_to(writeable);
+        8i64.write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(
+            RingbufferReadManyResponse::read_from(readable).unwrap(),
+            RingbufferReadManyResponse {
+                _read_count: 1,
+                items,
+                item_sequences: Some(vec![7]),
+                next_sequence: 8,
+            }
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use crate::serialization::Data;
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0401]
+pub(crate) struct TopicPublishRequest<'a> {
+    name: &'a str,
+    message: &'a Data,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> TopicPublishRequest<'a> {
+    pub(crate) fn new(name: &'a str, message: &'a Data, partition_id: i32) -> Self {
+        TopicPublishRequest {
+            name,
+            message,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x64]
+pub(crate) struct TopicPublishResponse {}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0402]
+pub(crate) struct TopicAddMessageListenerRequest {
+    name: String,
+    local_only: bool,
+}
+
+impl TopicAddMessageListenerRequest {
+    pub(crate) fn new(name: &str, local_only: bool) -> Self {
+        TopicAddMessageListenerRequest {
+            name: name.to_string(),
+            local_only,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x68]
+pub(crate) struct TopicAddMessageListenerResponse {
+    id: String,
+}
+
+impl TopicAddMessageListenerResponse {
+    pub(crate) fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0403]
+#[retryable]
+pub(crate) struct TopicRemoveMessageListenerRequest {
+    name: String,
+    id: String,
+}
+
+impl TopicRemoveMessageListenerRequest {
+    pub(crate) fn new(name: &str, id: &str) -> Self {
+        TopicRemoveMessageListenerRequest {
+            name: name.to_string(),
+            id: id.to_string(),
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct TopicRemoveMessageListenerResponse {
+    removed: bool,
+}
+
+impl TopicRemoveMessageListenerResponse {
+    pub(crate) fn removed(&self) -> bool {
+        self.removed
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0xCD]
+pub(crate) struct TopicEvent {
+    item: Data,
+    publish_time: i64,
+    member_id: String,
+}
+
+impl TopicEvent {
+    pub(crate) fn item(&self) -> &Data {
+        &self.item
+    }
+
+    pub(crate) fn publish_time(&self) -> i64 {
+        self.publish_time
+    }
+
+    pub(crate) fn member_id(&self) -> &str {
+        &self.member_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Request,
+        serialization::SerializationService,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_publish_request() {
+        let message = SerializationService::default().to_data(&"message".to_string());
+        let request = TopicPublishRequest::new("topic-name", &message, 13);
+        assert_eq!(request.partition_id(), 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Data::read_from(readable).unwrap(), message);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_topic_event() {
+        let item = SerializationService::default().to_data(&"message".to_string());
+        let member_id = "member-id".to_string();
+
+        let writeable = &mut BytesMut::new();
+        item.write_to(writeable);
+        1_600_000_000_000i64.write_to(writeable);
+        member_id.write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(
+            TopicEvent::read_from(readable).unwrap(),
+            TopicEvent {
+                item,
+                publish_time: 1_600_000_000_000,
+                member_id,
+            }
+        );
+    }
+}
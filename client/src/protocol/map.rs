@@ -0,0 +1,144 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use crate::{
+    messaging::map::{
+        MapClearRequest, MapClearResponse, MapContainsKeyRequest, MapContainsKeyResponse, MapGetRequest,
+        MapGetResponse, MapPutRequest, MapPutResponse, MapRemoveRequest, MapRemoveResponse, MapSizeRequest,
+        MapSizeResponse,
+    },
+    remote::cluster::Cluster,
+    serialization::{Data, SerializationService, Serializer},
+    Result,
+};
+
+const THREAD_ID: i64 = 1;
+
+pub struct IMap<K, V> {
+    name: String,
+    cluster: Arc<Cluster>,
+    serialization: SerializationService,
+
+    _marker: PhantomData<(K, V)>,
+}
+
+impl<K, V> IMap<K, V>
+where
+    K: Serializer,
+    V: Serializer,
+{
+    pub(crate) fn new(name: &str, cluster: Arc<Cluster>, serialization: SerializationService) -> Self {
+        IMap {
+            name: name.to_string(),
+            cluster,
+            serialization,
+            _marker: PhantomData,
+        }
+    }
+
+    pub async fn get(&self, key: &K) -> Result<Option<V>> {
+        let key = self.serialization.to_data(key);
+        let request = MapGetRequest::new(&self.name, &key, THREAD_ID, self.cluster.partition_id(&key));
+        let response: MapGetResponse = self.cluster.dispatch(request).await?;
+        self.to_object(response.value())
+    }
+
+    pub async fn put(&self, key: &K, value: &V) -> Result<Option<V>> {
+        let key = self.serialization.to_data(key);
+        let value = self.serialization.to_data(value);
+        let request = MapPutRequest::new(&self.name, &key, &value, THREAD_ID, self.cluster.partition_id(&key));
+        let response: MapPutResponse = self.cluster.dispatch(request).await?;
+        self.to_object(response.value())
+    }
+
+    pub async fn remove(&self, key: &K) -> Result<Option<V>> {
+        let key = self.serialization.to_data(key);
+        let request = MapRemoveRequest::new(&self.name, &key, THREAD_ID, self.cluster.partition_id(&key));
+        let response: MapRemoveResponse = self.cluster.dispatch(request).await?;
+        self.to_object(response.value())
+    }
+
+    pub async fn contains_key(&self, key: &K) -> Result<bool> {
+        let key = self.serialization.to_data(key);
+        let request = MapContainsKeyRequest::new(&self.name, &key, THREAD_ID, self.cluster.partition_id(&key));
+        let response: MapContainsKeyResponse = self.cluster.dispatch(request).await?;
+        Ok(response.contains())
+    }
+
+    pub async fn size(&self) -> Result<u32> {
+        let request = MapSizeRequest::new(&self.name);
+        let response: MapSizeResponse = self.cluster.dispatch(request).await?;
+        Ok(response.size())
+    }
+
+    pub async fn clear(&self) -> Result<()> {
+        let request = MapClearRequest::new(&self.name);
+        let _: MapClearResponse = self.cluster.dispatch(request).await?;
+        Ok(())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn to_object(&self, data: Option<&Data>) -> Result<Option<V>> {
+        data.map(|data| self.serialization.to_object(data)).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        messaging::{
+            map::{MapGetRequest, MapGetResponse},
+            partition::GetPartitionsRequest,
+            Request, Response,
+        },
+        remote::{
+            test_cluster::TestCluster,
+            test_server::{partitions, payload, Reply, TestServer},
+        },
+        serialization::Data,
+        HazelcastClient,
+    };
+
+    #[tokio::test]
+    async fn should_put_get_and_remove() {
+        let cluster = TestCluster::start(3).await.unwrap();
+        let client = HazelcastClient::new(HazelcastClient::builder().addresses(cluster.addresses()).build())
+            .await
+            .unwrap();
+        let map = client.map::<String, String>("some-map");
+        let key = "key".to_string();
+
+        assert_eq!(map.get(&key).await.unwrap(), None);
+        assert_eq!(map.put(&key, &"value".to_string()).await.unwrap(), None);
+        assert_eq!(
+            map.put(&key, &"other-value".to_string()).await.unwrap(),
+            Some("value".to_string())
+        );
+        assert!(map.contains_key(&key).await.unwrap());
+        assert_eq!(map.size().await.unwrap(), 1);
+        assert_eq!(map.remove(&key).await.unwrap(), Some("other-value".to_string()));
+        assert!(!map.contains_key(&key).await.unwrap());
+
+        map.put(&key, &"value".to_string()).await.unwrap();
+        map.clear().await.unwrap();
+        assert_eq!(map.size().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn should_not_panic_without_partition_table() {
+        let server = TestServer::start().await.unwrap();
+        server.respond(GetPartitionsRequest::r#type(), |_| vec![partitions(&[])]);
+        server.respond(MapGetRequest::r#type(), |_| {
+            vec![Reply::response(MapGetResponse::r#type(), payload(&[&None::<Data>]))]
+        });
+        let client = HazelcastClient::new(HazelcastClient::builder().address(server.address()).build())
+            .await
+            .unwrap();
+        let map = client.map::<String, String>("some-map");
+
+        assert_eq!(map.get(&"key".to_string()).await.unwrap(), None);
+        assert_eq!(server.received(MapGetRequest::r#type()), 1);
+    }
+}
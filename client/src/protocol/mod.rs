@@ -1 +1,5 @@
+pub mod map;
 pub mod pn_counter;
+pub mod queue;
+pub mod reliable_topic;
+pub mod topic;
@@ -67,3 +67,41 @@ impl PnCounter {
         &self.name
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{remote::test_cluster::TestCluster, HazelcastClient};
+
+    #[tokio::test]
+    async fn should_add_and_get() {
+        let cluster = TestCluster::start(3).await.unwrap();
+        let client = client(&cluster).await;
+        let mut counter = client.pn_counter("some-counter");
+
+        assert_eq!(counter.get().await.unwrap(), 0);
+        assert_eq!(counter.get_and_add(1).await.unwrap(), 0);
+        assert_eq!(counter.get_and_add(2).await.unwrap(), 1);
+        assert_eq!(counter.add_and_get(-1).await.unwrap(), 2);
+        assert_eq!(counter.get().await.unwrap(), 2);
+        assert_eq!(counter.replica_count().await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn should_observe_updates_of_other_replicas() {
+        let cluster = TestCluster::start(2).await.unwrap();
+        let client = client(&cluster).await;
+        let (mut counter, mut other) = (client.pn_counter("some-counter"), client.pn_counter("some-counter"));
+
+        counter.add_and_get(5).await.unwrap();
+        other.add_and_get(-2).await.unwrap();
+
+        assert_eq!(counter.get().await.unwrap(), 3);
+        assert_eq!(client.pn_counter("other-counter").get().await.unwrap(), 0);
+    }
+
+    async fn client(cluster: &TestCluster) -> HazelcastClient {
+        HazelcastClient::new(HazelcastClient::builder().addresses(cluster.addresses()).build())
+            .await
+            .unwrap()
+    }
+}
@@ -0,0 +1,352 @@
+use std::{convert::TryInto, marker::PhantomData, sync::Arc, time::Duration};
+
+use log::error;
+
+use crate::{
+    messaging::queue::{
+        self, QueueAddListenerRequest, QueueAddListenerResponse, QueueDrainToMaxSizeRequest,
+        QueueDrainToMaxSizeResponse, QueueOfferRequest, QueueOfferResponse, QueuePeekRequest, QueuePeekResponse,
+        QueuePollRequest, QueuePollResponse, QueueRemainingCapacityRequest, QueueRemainingCapacityResponse,
+        QueueRemoveListenerRequest, QueueRemoveListenerResponse, QueueSizeRequest, QueueSizeResponse, QueueTakeRequest,
+        QueueTakeResponse,
+    },
+    remote::{cluster::Cluster, listener::ListenerCodec},
+    serialization::{Data, SerializationService, Serializer},
+    HazelcastClientError::SerializationFailure,
+    Result,
+};
+
+pub struct IQueue<T> {
+    name: String,
+    name_data: Data,
+    cluster: Arc<Cluster>,
+    serialization: SerializationService,
+
+    _marker: PhantomData<T>,
+}
+
+impl<T> IQueue<T>
+where
+    T: Serializer,
+{
+    pub(crate) fn new(name: &str, cluster: Arc<Cluster>, serialization: SerializationService) -> Self {
+        IQueue {
+            name: name.to_string(),
+            name_data: serialization.to_data(&name.to_string()),
+            cluster,
+            serialization,
+            _marker: PhantomData,
+        }
+    }
+
+    pub async fn offer(&self, item: &T) -> Result<bool> {
+        let item = self.serialization.to_data(item);
+        let request = QueueOfferRequest::new(&self.name, &item, 0, self.partition_id());
+        let response: QueueOfferResponse = self.cluster.dispatch(request).await?;
+        Ok(response.offered())
+    }
+
+    pub async fn offer_timeout(&self, item: &T, timeout: Duration) -> Result<bool> {
+        let item = self.serialization.to_data(item);
+        let request = QueueOfferRequest::new(&self.name, &item, millis(timeout), self.partition_id());
+        let response: QueueOfferResponse = self.cluster.dispatch_blocking(request, Some(timeout)).await?;
+        Ok(response.offered())
+    }
+
+    pub async fn poll(&self) -> Result<Option<T>> {
+        let request = QueuePollRequest::new(&self.name, 0, self.partition_id());
+        let response: QueuePollResponse = self.cluster.dispatch(request).await?;
+        self.to_object(response.value())
+    }
+
+    pub async fn poll_timeout(&self, timeout: Duration) -> Result<Option<T>> {
+        let request = QueuePollRequest::new(&self.name, millis(timeout), self.partition_id());
+        let response: QueuePollResponse = self.cluster.dispatch_blocking(request, Some(timeout)).await?;
+        self.to_object(response.value())
+    }
+
+    pub async fn take(&self) -> Result<T> {
+        let request = QueueTakeRequest::new(&self.name, self.partition_id());
+        let response: QueueTakeResponse = self.cluster.dispatch_blocking(request, None).await?;
+        self.to_object(response.value())?
+            .ok_or_else(|| SerializationFailure("unexpected null value".to_string()))
+    }
+
+    pub async fn peek(&self) -> Result<Option<T>> {
+        let request = QueuePeekRequest::new(&self.name, self.partition_id());
+        let response: QueuePeekResponse = self.cluster.dispatch(request).await?;
+        self.to_object(response.value())
+    }
+
+    pub async fn size(&self) -> Result<u32> {
+        let request = QueueSizeRequest::new(&self.name, self.partition_id());
+        let response: QueueSizeResponse = self.cluster.dispatch(request).await?;
+        Ok(response.size())
+    }
+
+    pub async fn remaining_capacity(&self) -> Result<u32> {
+        let request = QueueRemainingCapacityRequest::new(&self.name, self.partition_id());
+        let response: QueueRemainingCapacityResponse = self.cluster.dispatch(request).await?;
+        Ok(response.capacity())
+    }
+
+    pub async fn drain_to(&self, items: &mut Vec<T>) -> Result<usize> {
+        self.drain(items, -1).await
+    }
+
+    pub async fn drain_to_max(&self, items: &mut Vec<T>, max_size: u32) -> Result<usize> {
+        self.drain(items, max_size.try_into().unwrap_or(i32::MAX)).await
+    }
+
+    pub async fn add_item_listener<L>(&self, include_value: bool, listener: L) -> Result<String>
+    where
+        T: Send + 'static,
+        L: Fn(ItemEvent<T>) + Send + Sync + 'static,
+    {
+        let codec = ItemListener {
+            name: self.name.clone(),
+            include_value,
+        };
+        let serialization = self.serialization.clone();
+        self.cluster
+            .add_listener(codec, move |event| match ItemEvent::from(&event, &serialization) {
+                Ok(event) => listener(event),
+                Err(e) => error!("Failed to handle item event - {}", e),
+            })
+            .await
+    }
+
+    pub async fn remove_item_listener(&self, id: &str) -> Result<bool> {
+        self.cluster.remove_listener(id).await
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn drain(&self, items: &mut Vec<T>, max_size: i32) -> Result<usize> {
+        let request = QueueDrainToMaxSizeRequest::new(&self.name, max_size, self.partition_id());
+        let response: QueueDrainToMaxSizeResponse = self.cluster.dispatch(request).await?;
+        for value in response.values() {
+            items.push(self.serialization.to_object(value)?);
+        }
+        Ok(response.values().len())
+    }
+
+    fn partition_id(&self) -> i32 {
+        self.cluster.partition_id(&self.name_data)
+    }
+
+    fn to_object(&self, data: Option<&Data>) -> Result<Option<T>> {
+        data.map(|data| self.serialization.to_object(data)).transpose()
+    }
+}
+
+struct ItemListener {
+    name: String,
+    include_value: bool,
+}
+
+impl ListenerCodec for ItemListener {
+    type AddRequest = QueueAddListenerRequest;
+    type AddResponse = QueueAddListenerResponse;
+    type RemoveRequest = QueueRemoveListenerRequest;
+    type RemoveResponse = QueueRemoveListenerResponse;
+    type Event = queue::ItemEvent;
+
+    fn add_request(&self) -> Self::AddRequest {
+        QueueAddListenerRequest::new(&self.name, self.include_value, false)
+    }
+
+    fn registration_id(response: &Self::AddResponse) -> &str {
+        response.id()
+    }
+
+    fn remove_request(&self, registration_id: &str) -> Self::RemoveRequest {
+        QueueRemoveListenerRequest::new(&self.name, registration_id)
+    }
+
+    fn removed(response: &Self::RemoveResponse) -> bool {
+        response.removed()
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum ItemEventType {
+    Added,
+    Removed,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct ItemEvent<T> {
+    item: Option<T>,
+    member_id: String,
+    event_type: ItemEventType,
+}
+
+impl<T: Serializer> ItemEvent<T> {
+    fn from(event: &queue::ItemEvent, serialization: &SerializationService) -> Result<Self> {
+        let event_type = match event.event_type() {
+            queue::ITEM_ADDED => ItemEventType::Added,
+            queue::ITEM_REMOVED => ItemEventType::Removed,
+            event_type => {
+                return Err(SerializationFailure(format!("unknown item event type: {}", event_type)));
+            }
+        };
+        Ok(ItemEvent {
+            item: event.item().map(|item| serialization.to_object(item)).transpose()?,
+            member_id: event.member_id().to_string(),
+            event_type,
+        })
+    }
+}
+
+impl<T> ItemEvent<T> {
+    pub fn item(&self) -> Option<&T> {
+        self.item.as_ref()
+    }
+
+    pub fn member_id(&self) -> &str {
+        &self.member_id
+    }
+
+    pub fn event_type(&self) -> ItemEventType {
+        self.event_type
+    }
+}
+
+fn millis(timeout: Duration) -> i64 {
+    timeout.as_millis().try_into().unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    use tokio::{sync::mpsc, time};
+
+    use crate::{
+        messaging::{
+            partition::GetPartitionsRequest,
+            queue::{QueueSizeRequest, QueueSizeResponse},
+            Address, Request, Response,
+        },
+        remote::{
+            test_cluster::TestCluster,
+            test_server::{partitions, payload, Reply, TestServer},
+        },
+        HazelcastClient, ItemEventType,
+    };
+
+    #[tokio::test]
+    async fn should_offer_poll_and_drain() {
+        let cluster = TestCluster::start(3).await.unwrap();
+        let client = HazelcastClient::new(HazelcastClient::builder().addresses(cluster.addresses()).build())
+            .await
+            .unwrap();
+        let queue = client.queue::<String>("some-queue");
+
+        assert_eq!(queue.poll().await.unwrap(), None);
+        assert!(queue.offer(&"first".to_string()).await.unwrap());
+        assert!(queue
+            .offer_timeout(&"second".to_string(), Duration::from_secs(1))
+            .await
+            .unwrap());
+        assert!(queue.offer(&"third".to_string()).await.unwrap());
+        assert_eq!(queue.size().await.unwrap(), 3);
+        assert_eq!(queue.remaining_capacity().await.unwrap(), i32::MAX as u32 - 3);
+        assert_eq!(queue.peek().await.unwrap(), Some("first".to_string()));
+        assert_eq!(queue.poll().await.unwrap(), Some("first".to_string()));
+
+        let mut items = vec![];
+        assert_eq!(queue.drain_to_max(&mut items, 1).await.unwrap(), 1);
+        assert_eq!(queue.drain_to(&mut items).await.unwrap(), 1);
+        assert_eq!(items, vec!["second".to_string(), "third".to_string()]);
+        assert_eq!(queue.size().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn should_wait_for_items_beyond_invocation_timeout() {
+        let cluster = TestCluster::start(1).await.unwrap();
+        let config = HazelcastClient::builder()
+            .addresses(cluster.addresses())
+            .invocation_timeout(Duration::from_millis(200))
+            .build();
+        let client = HazelcastClient::new(config).await.unwrap();
+        let queue = client.queue::<String>("some-queue");
+
+        assert_eq!(queue.poll_timeout(Duration::from_millis(100)).await.unwrap(), None);
+
+        let (taken, offered) = tokio::join!(queue.take(), async {
+            time::delay_for(Duration::from_millis(400)).await;
+            assert_eq!(queue.size().await.unwrap(), 0);
+            queue.offer(&"item".to_string()).await.unwrap()
+        });
+        assert!(offered);
+        assert_eq!(taken.unwrap(), "item".to_string());
+    }
+
+    #[tokio::test]
+    async fn should_notify_item_listeners() {
+        let cluster = TestCluster::start(3).await.unwrap();
+        let client = HazelcastClient::new(HazelcastClient::builder().addresses(cluster.addresses()).build())
+            .await
+            .unwrap();
+        let queue = client.queue::<String>("some-queue");
+
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let id = queue
+            .add_item_listener(true, move |event| {
+                let _ = sender.send((event.event_type(), event.item().cloned()));
+            })
+            .await
+            .unwrap();
+
+        queue.offer(&"item".to_string()).await.unwrap();
+        queue.poll().await.unwrap();
+        assert_eq!(
+            receiver.recv().await,
+            Some((ItemEventType::Added, Some("item".to_string())))
+        );
+        assert_eq!(
+            receiver.recv().await,
+            Some((ItemEventType::Removed, Some("item".to_string())))
+        );
+
+        assert!(queue.remove_item_listener(&id).await.unwrap());
+        assert_eq!(receiver.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn should_resolve_partition_after_refresh() {
+        let server = TestServer::start().await.unwrap();
+        let owners = vec![Address::from(&server.address())];
+        let attempts = AtomicUsize::new(0);
+        server.respond(GetPartitionsRequest::r#type(), move |_| {
+            match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 => vec![partitions(&[])],
+                _ => vec![partitions(&owners)],
+            }
+        });
+        server.respond(QueueSizeRequest::r#type(), |_| {
+            vec![Reply::response(QueueSizeResponse::r#type(), payload(&[&0u32]))]
+        });
+        let client = HazelcastClient::new(HazelcastClient::builder().address(server.address()).build())
+            .await
+            .unwrap();
+        let queue = client.queue::<String>("some-queue");
+
+        assert_eq!(queue.size().await.unwrap(), 0);
+        time::timeout(Duration::from_secs(5), async {
+            while queue.partition_id() == -1 {
+                time::delay_for(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(server.received(QueueSizeRequest::r#type()), 1);
+    }
+}
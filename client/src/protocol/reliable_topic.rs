@@ -0,0 +1,504 @@
+use std::{
+    marker::PhantomData,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use log::warn;
+use tokio::{
+    stream::Stream,
+    sync::{mpsc, oneshot},
+    time,
+};
+
+use crate::{
+    messaging::{
+        ringbuffer::{
+            RingbufferAddRequest, RingbufferAddResponse, RingbufferHeadSequenceRequest, RingbufferHeadSequenceResponse,
+            RingbufferReadManyRequest, RingbufferReadManyResponse, RingbufferTailSequenceRequest,
+            RingbufferTailSequenceResponse, FAIL, OVERWRITE,
+        },
+        Address,
+    },
+    remote::cluster::Cluster,
+    serialization::{Data, ObjectDataInput, ObjectDataOutput, SerializationService, Serializer},
+    HazelcastClientError::{SerializationFailure, StaleSequence, TopicOverloaded},
+    Result,
+};
+
+const RINGBUFFER_PREFIX: &str = "_hz_rb_";
+const READ_BATCH_SIZE: i32 = 10;
+const INITIAL_PUBLISH_BACKOFF: Duration = Duration::from_millis(100);
+const MAX_PUBLISH_BACKOFF: Duration = Duration::from_secs(2);
+
+const NULL_TYPE_ID: i32 = 0;
+const IDENTIFIED_DATA_SERIALIZABLE_TYPE_ID: i32 = -2;
+const IDENTIFIED_FLAG: i8 = 1;
+const VERSIONED_FLAG: i8 = 2;
+const CLUSTER_FACTORY_ID: i32 = 0;
+const ADDRESS_CLASS_ID: i32 = 1;
+const TOPIC_FACTORY_ID: i32 = -18;
+const RELIABLE_TOPIC_MESSAGE_CLASS_ID: i32 = 2;
+const IPV4: i8 = 4;
+const IPV6: i8 = 6;
+
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum LossPolicy {
+    Fail,
+    JumpToHead,
+}
+
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Default)]
+pub enum OverloadPolicy {
+    DiscardOldest,
+    DiscardNewest,
+    #[default]
+    Block,
+    Error,
+}
+
+pub struct ReliableTopic<T> {
+    name: String,
+    ringbuffer: String,
+    ringbuffer_data: Data,
+    overload_policy: OverloadPolicy,
+    cluster: Arc<Cluster>,
+    serialization: SerializationService,
+
+    _marker: PhantomData<T>,
+}
+
+impl<T> ReliableTopic<T>
+where
+    T: Serializer,
+{
+    pub(crate) fn new(name: &str, cluster: Arc<Cluster>, serialization: SerializationService) -> Self {
+        let ringbuffer = format!("{}{}", RINGBUFFER_PREFIX, name);
+        ReliableTopic {
+            name: name.to_string(),
+            ringbuffer_data: serialization.to_data(&ringbuffer),
+            ringbuffer,
+            overload_policy: OverloadPolicy::default(),
+            cluster,
+            serialization,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn with_overload_policy(mut self, overload_policy: OverloadPolicy) -> Self {
+        self.overload_policy = overload_policy;
+        self
+    }
+
+    pub async fn publish(&self, message: &T) -> Result<()> {
+        let message = ReliableTopicMessage {
+            publish_time: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_millis() as i64)
+                .unwrap_or(0),
+            publisher: None,
+            payload: self.serialization.to_data(message),
+        };
+        let message = self.serialization.to_data(&message);
+        let overflow_policy = match self.overload_policy {
+            OverloadPolicy::DiscardOldest => OVERWRITE,
+            _ => FAIL,
+        };
+        let mut backoff = INITIAL_PUBLISH_BACKOFF;
+        loop {
+            let request = RingbufferAddRequest::new(&self.ringbuffer, overflow_policy, &message, self.partition_id());
+            let response: RingbufferAddResponse = self.cluster.dispatch(request).await?;
+            if response.sequence() != -1 {
+                return Ok(());
+            }
+            match self.overload_policy {
+                OverloadPolicy::DiscardOldest | OverloadPolicy::DiscardNewest => return Ok(()),
+                OverloadPolicy::Error => return Err(TopicOverloaded(self.name.clone())),
+                OverloadPolicy::Block => {
+                    time::delay_for(backoff).await;
+                    backoff = (backoff * 2).min(MAX_PUBLISH_BACKOFF);
+                }
+            }
+        }
+    }
+
+    pub async fn subscribe(&self, loss_policy: LossPolicy) -> Result<ReliableSubscription<T>>
+    where
+        T: Send + 'static,
+    {
+        let request = RingbufferTailSequenceRequest::new(&self.ringbuffer, self.partition_id());
+        let response: RingbufferTailSequenceResponse = self.cluster.dispatch(request).await?;
+        Ok(self.subscribe_from(response.sequence() + 1, loss_policy))
+    }
+
+    pub fn subscribe_from(&self, sequence: i64, loss_policy: LossPolicy) -> ReliableSubscription<T>
+    where
+        T: Send + 'static,
+    {
+        let (sender, messages) = mpsc::channel(READ_BATCH_SIZE as usize);
+        let (handle, receiver) = oneshot::channel();
+        let reader = Reader {
+            name: self.name.clone(),
+            ringbuffer: self.ringbuffer.clone(),
+            ringbuffer_data: self.ringbuffer_data.clone(),
+            cluster: self.cluster.clone(),
+            serialization: self.serialization.clone(),
+            loss_policy,
+            sequence,
+            _marker: PhantomData,
+        };
+        tokio::spawn(reader.read(sender, receiver));
+        ReliableSubscription {
+            messages,
+            _handle: handle,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn partition_id(&self) -> i32 {
+        self.cluster.partition_id(&self.ringbuffer_data)
+    }
+}
+
+pub struct ReliableSubscription<T> {
+    messages: mpsc::Receiver<Result<ReliableMessage<T>>>,
+    _handle: oneshot::Sender<()>,
+}
+
+impl<T> Stream for ReliableSubscription<T> {
+    type Item = Result<ReliableMessage<T>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.messages).poll_next(cx)
+    }
+}
+
+struct Reader<T> {
+    name: String,
+    ringbuffer: String,
+    ringbuffer_data: Data,
+    cluster: Arc<Cluster>,
+    serialization: SerializationService,
+    loss_policy: LossPolicy,
+    sequence: i64,
+
+    _marker: PhantomData<T>,
+}
+
+impl<T> Reader<T>
+where
+    T: Serializer,
+{
+    async fn read(mut self, mut sender: mpsc::Sender<Result<ReliableMessage<T>>>, mut receiver: oneshot::Receiver<()>) {
+        loop {
+            let messages = tokio::select! {
+                _ = &mut receiver => return,
+                messages = self.read_many() => messages,
+            };
+            match messages {
+                Ok(messages) => {
+                    for message in messages {
+                        if sender.send(message).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                Err(e) => {
+                    let _ = sender.send(Err(e)).await;
+                    return;
+                }
+            }
+        }
+    }
+
+    async fn read_many(&mut self) -> Result<Vec<Result<ReliableMessage<T>>>> {
+        loop {
+            let request = RingbufferReadManyRequest::new(
+                &self.ringbuffer,
+                self.sequence,
+                1,
+                READ_BATCH_SIZE,
+                self.partition_id(),
+            );
+            match self
+                .cluster
+                .dispatch_blocking::<_, RingbufferReadManyResponse>(request, None)
+                .await
+            {
+                Ok(response) => {
+                    let sequences = response.item_sequences();
+                    let messages = response
+                        .items()
+                        .iter()
+                        .enumerate()
+                        .map(|(i, item)| {
+                            let sequence = sequences
+                                .and_then(|sequences| sequences.get(i).copied())
+                                .unwrap_or(self.sequence + i as i64);
+                            self.to_message(item, sequence)
+                        })
+                        .collect();
+                    self.sequence = response.next_sequence();
+                    return Ok(messages);
+                }
+                Err(StaleSequence(e)) if self.loss_policy == LossPolicy::JumpToHead => {
+                    let request = RingbufferHeadSequenceRequest::new(&self.ringbuffer, self.partition_id());
+                    let response: RingbufferHeadSequenceResponse = self.cluster.dispatch(request).await?;
+                    warn!(
+                        "Reliable topic {} lost messages from sequence {} to {} - {}",
+                        self.name,
+                        self.sequence,
+                        response.sequence() - 1,
+                        e
+                    );
+                    self.sequence = response.sequence();
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn partition_id(&self) -> i32 {
+        self.cluster.partition_id(&self.ringbuffer_data)
+    }
+
+    fn to_message(&self, item: &Data, sequence: i64) -> Result<ReliableMessage<T>> {
+        let message: ReliableTopicMessage = self.serialization.to_object(item)?;
+        Ok(ReliableMessage {
+            payload: self.serialization.to_object(&message.payload)?,
+            publish_time: UNIX_EPOCH + Duration::from_millis(message.publish_time.max(0) as u64),
+            publisher: message.publisher,
+            sequence,
+        })
+    }
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct ReliableMessage<T> {
+    payload: T,
+    publish_time: SystemTime,
+    publisher: Option<Address>,
+    sequence: i64,
+}
+
+impl<T> ReliableMessage<T> {
+    pub fn payload(&self) -> &T {
+        &self.payload
+    }
+
+    pub fn into_payload(self) -> T {
+        self.payload
+    }
+
+    pub fn publish_time(&self) -> SystemTime {
+        self.publish_time
+    }
+
+    pub fn publisher(&self) -> Option<&Address> {
+        self.publisher.as_ref()
+    }
+
+    pub fn sequence(&self) -> i64 {
+        self.sequence
+    }
+}
+
+#[derive(Eq, PartialEq, Debug)]
+struct ReliableTopicMessage {
+    publish_time: i64,
+    publisher: Option<Address>,
+    payload: Data,
+}
+
+impl Serializer for ReliableTopicMessage {
+    fn type_id() -> i32 {
+        IDENTIFIED_DATA_SERIALIZABLE_TYPE_ID
+    }
+
+    fn write_to(&self, output: &mut ObjectDataOutput) {
+        write_header(output, TOPIC_FACTORY_ID, RELIABLE_TOPIC_MESSAGE_CLASS_ID);
+        output.write_i64(self.publish_time);
+        match &self.publisher {
+            Some(publisher) => {
+                output.write_i32(IDENTIFIED_DATA_SERIALIZABLE_TYPE_ID);
+                write_header(output, CLUSTER_FACTORY_ID, ADDRESS_CLASS_ID);
+                output.write_i32(publisher.port() as i32);
+                output.write_i8(if publisher.host().contains(':') { IPV6 } else { IPV4 });
+                output.write_bytes(publisher.host().as_bytes());
+            }
+            None => output.write_i32(NULL_TYPE_ID),
+        }
+        output.write_data(&self.payload);
+    }
+
+    fn read_from(input: &mut ObjectDataInput) -> Result<Self> {
+        read_header(input, TOPIC_FACTORY_ID, RELIABLE_TOPIC_MESSAGE_CLASS_ID)?;
+        let publish_time = input.read_i64()?;
+        let publisher = match input.read_i32()? {
+            NULL_TYPE_ID => None,
+            IDENTIFIED_DATA_SERIALIZABLE_TYPE_ID => {
+                read_header(input, CLUSTER_FACTORY_ID, ADDRESS_CLASS_ID)?;
+                let port = input.read_i32()?;
+                let _type = input.read_i8()?;
+                let host = String::from_utf8(input.read_bytes()?).map_err(|e| SerializationFailure(e.to_string()))?;
+                Some(Address::new(&host, port as u32))
+            }
+            type_id => {
+                return Err(SerializationFailure(format!(
+                    "unexpected publisher type id: {}",
+                    type_id
+                )))
+            }
+        };
+        Ok(ReliableTopicMessage {
+            publish_time,
+            publisher,
+            payload: input.read_data()?,
+        })
+    }
+}
+
+fn write_header(output: &mut ObjectDataOutput, factory_id: i32, class_id: i32) {
+    output.write_i8(IDENTIFIED_FLAG);
+    output.write_i32(factory_id);
+    output.write_i32(class_id);
+}
+
+fn read_header(input: &mut ObjectDataInput, factory_id: i32, class_id: i32) -> Result<()> {
+    let flags = input.read_i8()?;
+    if flags & IDENTIFIED_FLAG == 0 {
+        return Err(SerializationFailure(
+            "unexpected non-identified data serializable".to_string(),
+        ));
+    }
+    match (input.read_i32()?, input.read_i32()?) {
+        (factory, class) if factory == factory_id && class == class_id => {}
+        (factory, class) => {
+            return Err(SerializationFailure(format!(
+                "unexpected factory id: {}, class id: {}, expected: {}, {}",
+                factory, class, factory_id, class_id
+            )))
+        }
+    }
+    if flags & VERSIONED_FLAG != 0 {
+        input.read_i8()?;
+        input.read_i8()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::{stream::StreamExt, time};
+
+    use crate::{remote::test_cluster::TestCluster, HazelcastClient};
+
+    use super::*;
+
+    #[test]
+    fn should_serialize_and_deserialize_reliable_topic_message() {
+        let service = SerializationService::default();
+        let message = ReliableTopicMessage {
+            publish_time: 69,
+            publisher: Some(Address::new("127.0.0.1", 5701)),
+            payload: service.to_data(&"payload".to_string()),
+        };
+
+        let data = service.to_data(&message);
+        assert_eq!(service.to_object::<ReliableTopicMessage>(&data).unwrap(), message);
+        assert!(service.to_object::<String>(&data).is_err());
+    }
+
+    #[tokio::test]
+    async fn should_deliver_published_messages_in_order() {
+        let cluster = TestCluster::start(3).await.unwrap();
+        let client = HazelcastClient::new(HazelcastClient::builder().addresses(cluster.addresses()).build())
+            .await
+            .unwrap();
+        let topic = client.reliable_topic::<String>("some-topic");
+        topic.publish(&"missed".to_string()).await.unwrap();
+        let mut subscription = topic.subscribe(LossPolicy::Fail).await.unwrap();
+
+        for i in 0..3 {
+            topic.publish(&format!("message-{}", i)).await.unwrap();
+        }
+
+        for i in 0..3 {
+            let message = time::timeout(Duration::from_secs(5), subscription.next())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap();
+            assert_eq!(message.payload(), &format!("message-{}", i));
+            assert_eq!(message.sequence(), i + 1);
+            assert!(message.publish_time() > UNIX_EPOCH);
+            assert_eq!(message.publisher(), None);
+        }
+
+        let mut resumed = topic.subscribe_from(2, LossPolicy::Fail);
+        let message = resumed.next().await.unwrap().unwrap();
+        assert_eq!(
+            (message.sequence(), message.into_payload()),
+            (2, "message-1".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_handle_stale_sequence_according_to_loss_policy() {
+        let cluster = TestCluster::start(1).await.unwrap();
+        let client = HazelcastClient::new(HazelcastClient::builder().addresses(cluster.addresses()).build())
+            .await
+            .unwrap();
+        let topic = client
+            .reliable_topic::<i64>("some-topic")
+            .with_overload_policy(OverloadPolicy::DiscardOldest);
+        for i in 0..20 {
+            topic.publish(&i).await.unwrap();
+        }
+
+        let mut failing = topic.subscribe_from(0, LossPolicy::Fail);
+        assert!(matches!(failing.next().await, Some(Err(StaleSequence(_)))));
+        assert!(failing.next().await.is_none());
+
+        let mut jumping = topic.subscribe_from(0, LossPolicy::JumpToHead);
+        let message = jumping.next().await.unwrap().unwrap();
+        assert_eq!(message.sequence(), 4);
+        assert_eq!(message.into_payload(), 4);
+    }
+
+    #[tokio::test]
+    async fn should_handle_full_ringbuffer_according_to_overload_policy() {
+        let cluster = TestCluster::start(1).await.unwrap();
+        let client = HazelcastClient::new(HazelcastClient::builder().addresses(cluster.addresses()).build())
+            .await
+            .unwrap();
+        let topic = client.reliable_topic::<i64>("some-topic");
+        for i in 0..16 {
+            topic.publish(&i).await.unwrap();
+        }
+
+        assert!(time::timeout(Duration::from_millis(300), topic.publish(&16))
+            .await
+            .is_err());
+
+        let topic = topic.with_overload_policy(OverloadPolicy::Error);
+        assert!(matches!(topic.publish(&16).await, Err(TopicOverloaded(_))));
+
+        let topic = topic.with_overload_policy(OverloadPolicy::DiscardNewest);
+        topic.publish(&16).await.unwrap();
+        let message = topic.subscribe_from(0, LossPolicy::Fail).next().await.unwrap().unwrap();
+        assert_eq!(message.into_payload(), 0);
+
+        let topic = topic.with_overload_policy(OverloadPolicy::DiscardOldest);
+        topic.publish(&16).await.unwrap();
+        let message = topic.subscribe_from(1, LossPolicy::Fail).next().await.unwrap().unwrap();
+        assert_eq!(message.into_payload(), 1);
+    }
+}
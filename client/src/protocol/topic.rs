@@ -0,0 +1,249 @@
+use std::{
+    marker::PhantomData,
+    mem,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use log::{debug, error};
+use tokio::{runtime::Handle, stream::Stream, sync::mpsc};
+
+use crate::{
+    messaging::topic::{
+        TopicAddMessageListenerRequest, TopicAddMessageListenerResponse, TopicEvent, TopicPublishRequest,
+        TopicPublishResponse, TopicRemoveMessageListenerRequest, TopicRemoveMessageListenerResponse,
+    },
+    remote::{cluster::Cluster, listener::ListenerCodec},
+    serialization::{Data, SerializationService, Serializer},
+    Result,
+};
+
+pub struct ITopic<T> {
+    name: String,
+    name_data: Data,
+    cluster: Arc<Cluster>,
+    serialization: SerializationService,
+
+    _marker: PhantomData<T>,
+}
+
+impl<T> ITopic<T>
+where
+    T: Serializer,
+{
+    pub(crate) fn new(name: &str, cluster: Arc<Cluster>, serialization: SerializationService) -> Self {
+        ITopic {
+            name: name.to_string(),
+            name_data: serialization.to_data(&name.to_string()),
+            cluster,
+            serialization,
+            _marker: PhantomData,
+        }
+    }
+
+    pub async fn publish(&self, message: &T) -> Result<()> {
+        let message = self.serialization.to_data(message);
+        let request = TopicPublishRequest::new(&self.name, &message, self.partition_id());
+        let _: TopicPublishResponse = self.cluster.dispatch(request).await?;
+        Ok(())
+    }
+
+    pub async fn subscribe(&self) -> Result<Subscription<T>>
+    where
+        T: Send + 'static,
+    {
+        let codec = MessageListener {
+            name: self.name.clone(),
+        };
+        let (sender, messages) = mpsc::unbounded_channel();
+        let (name, serialization) = (self.name.clone(), self.serialization.clone());
+        let id = self
+            .cluster
+            .add_listener(codec, move |event: TopicEvent| {
+                match serialization.to_object(event.item()) {
+                    Ok(payload) => {
+                        let _ = sender.send(TopicMessage {
+                            payload,
+                            publish_time: UNIX_EPOCH + Duration::from_millis(event.publish_time().max(0) as u64),
+                            member_id: event.member_id().to_string(),
+                        });
+                    }
+                    Err(e) => error!("Failed to deserialize message published to {} - {}", name, e),
+                }
+            })
+            .await?;
+        Ok(Subscription {
+            id,
+            cluster: self.cluster.clone(),
+            messages,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn partition_id(&self) -> i32 {
+        self.cluster.partition_id(&self.name_data)
+    }
+}
+
+pub struct Subscription<T> {
+    id: String,
+    cluster: Arc<Cluster>,
+    messages: mpsc::UnboundedReceiver<TopicMessage<T>>,
+}
+
+impl<T> Subscription<T> {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub async fn unsubscribe(mut self) -> Result<bool> {
+        let id = mem::take(&mut self.id);
+        self.cluster.remove_listener(&id).await
+    }
+}
+
+impl<T> Stream for Subscription<T> {
+    type Item = TopicMessage<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.messages).poll_next(cx)
+    }
+}
+
+impl<T> Drop for Subscription<T> {
+    fn drop(&mut self) {
+        if self.id.is_empty() {
+            return;
+        }
+
+        if let Ok(runtime) = Handle::try_current() {
+            let (id, cluster) = (mem::take(&mut self.id), self.cluster.clone());
+            runtime.spawn(async move {
+                if let Err(e) = cluster.remove_listener(&id).await {
+                    debug!("Failed to remove message listener {} - {}", id, e);
+                }
+            });
+        }
+    }
+}
+
+struct MessageListener {
+    name: String,
+}
+
+impl ListenerCodec for MessageListener {
+    type AddRequest = TopicAddMessageListenerRequest;
+    type AddResponse = TopicAddMessageListenerResponse;
+    type RemoveRequest = TopicRemoveMessageListenerRequest;
+    type RemoveResponse = TopicRemoveMessageListenerResponse;
+    type Event = TopicEvent;
+
+    fn add_request(&self) -> Self::AddRequest {
+        TopicAddMessageListenerRequest::new(&self.name, false)
+    }
+
+    fn registration_id(response: &Self::AddResponse) -> &str {
+        response.id()
+    }
+
+    fn remove_request(&self, registration_id: &str) -> Self::RemoveRequest {
+        TopicRemoveMessageListenerRequest::new(&self.name, registration_id)
+    }
+
+    fn removed(response: &Self::RemoveResponse) -> bool {
+        response.removed()
+    }
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct TopicMessage<T> {
+    payload: T,
+    publish_time: SystemTime,
+    member_id: String,
+}
+
+impl<T> TopicMessage<T> {
+    pub fn payload(&self) -> &T {
+        &self.payload
+    }
+
+    pub fn into_payload(self) -> T {
+        self.payload
+    }
+
+    pub fn publish_time(&self) -> SystemTime {
+        self.publish_time
+    }
+
+    pub fn member_id(&self) -> &str {
+        &self.member_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use tokio::{stream::StreamExt, time};
+
+    use crate::{
+        messaging::{topic::TopicRemoveMessageListenerRequest, Request},
+        remote::test_cluster::TestCluster,
+        HazelcastClient,
+    };
+
+    #[tokio::test]
+    async fn should_deliver_published_messages_to_subscribers() {
+        let cluster = TestCluster::start(3).await.unwrap();
+        let client = HazelcastClient::new(HazelcastClient::builder().addresses(cluster.addresses()).build())
+            .await
+            .unwrap();
+        let topic = client.topic::<String>("some-topic");
+        let mut first = topic.subscribe().await.unwrap();
+        let mut second = topic.subscribe().await.unwrap();
+
+        topic.publish(&"message".to_string()).await.unwrap();
+        topic.publish(&"other-message".to_string()).await.unwrap();
+
+        for subscription in &mut [&mut first, &mut second] {
+            let message = subscription.next().await.unwrap();
+            assert_eq!(message.payload(), "message");
+            assert!(message.publish_time() > UNIX_EPOCH);
+            assert!(!message.member_id().is_empty());
+            assert_eq!(subscription.next().await.unwrap().into_payload(), "other-message");
+        }
+    }
+
+    #[tokio::test]
+    async fn should_remove_listener_on_unsubscribe_and_drop() {
+        let cluster = TestCluster::start(3).await.unwrap();
+        let client = HazelcastClient::new(HazelcastClient::builder().addresses(cluster.addresses()).build())
+            .await
+            .unwrap();
+        let topic = client.topic::<String>("some-topic");
+
+        let subscription = topic.subscribe().await.unwrap();
+        assert!(subscription.unsubscribe().await.unwrap());
+        drop(topic.subscribe().await.unwrap());
+
+        let removed = || {
+            cluster
+                .members()
+                .iter()
+                .map(|member| member.received(TopicRemoveMessageListenerRequest::r#type()))
+                .sum::<usize>()
+        };
+        for _ in 0..100 {
+            if removed() == 2 {
+                break;
+            }
+            time::delay_for(Duration::from_millis(10)).await;
+        }
+        assert_eq!(removed(), 2);
+    }
+}
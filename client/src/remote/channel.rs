@@ -1,128 +1,546 @@
 use std::{
+    collections::HashMap,
+    convert::TryInto,
     error::Error,
+    future::Future,
     net::SocketAddr,
     pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
     task::{Context, Poll},
+    time::{Duration, Instant},
 };
 
 use bytes::{Buf, Bytes, BytesMut};
+use log::{debug, warn};
 use tokio::{
-    net::tcp::{ReadHalf, WriteHalf},
+    io::{self as tokio_io, ReadHalf, WriteHalf},
+    net::TcpStream,
     prelude::*,
     stream::Stream,
     sync::{mpsc, oneshot},
+    task::JoinHandle,
+    time::{self, Delay, Interval},
 };
 use tokio_util::codec::{FramedRead, FramedWrite, LengthDelimitedCodec};
 
-use crate::remote::{Message, LENGTH_FIELD_ADJUSTMENT, LENGTH_FIELD_LENGTH, LENGTH_FIELD_OFFSET, PROTOCOL_SEQUENCE};
+#[cfg(feature = "tls")]
+use crate::remote::tls::Connector;
+use crate::{
+    codec,
+    config::ClientConfig,
+    remote::{
+        Header, Message, BEGIN_MESSAGE, END_MESSAGE, HEADER_LENGTH, LENGTH_FIELD_ADJUSTMENT, LENGTH_FIELD_LENGTH,
+        LENGTH_FIELD_OFFSET, PROTOCOL_SEQUENCE, UNFRAGMENTED_MESSAGE,
+    },
+    HazelcastClientError::{ClientShutdown, CommunicationFailure, ConnectionClosed, Overloaded},
+};
+
+const MAX_FRAME_LENGTH: usize = i32::MAX as usize;
+const FLAGS_FIELD_OFFSET: usize = 1;
+const CLOSED_BY_PEER: &str = "connection closed by peer";
+const MAX_PARTIAL_MESSAGES: usize = 1024;
+const PRUNE_INTERVAL: Duration = Duration::from_secs(10);
 
 type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
-type Responder = oneshot::Sender<Message>;
+type Responder = oneshot::Sender<crate::Result<Message>>;
+type Listener = mpsc::UnboundedSender<Message>;
+type Connection = Box<dyn Transport + Send + Unpin>;
+
+trait Transport: AsyncRead + AsyncWrite {}
+
+impl<T: AsyncRead + AsyncWrite> Transport for T {}
+
+enum Closing {
+    Graceful(Instant),
+    Abort(String),
+}
 
 enum Event {
-    Egress((Message, Responder)),
+    Egress((Message, Responder, Option<Listener>)),
     Ingress(BytesMut),
+    Unsubscribe(u64),
+    Close,
+    Expire,
+    Prune,
+}
+
+#[derive(Clone)]
+pub(in crate::remote) struct ChannelOptions {
+    frame_size: usize,
+    egress_capacity: usize,
+    fail_on_overload: bool,
+    metrics: Arc<Metrics>,
+    #[cfg(feature = "tls")]
+    tls: Option<Arc<Connector>>,
+}
+
+impl ChannelOptions {
+    pub(in crate::remote) fn new(config: &ClientConfig) -> crate::Result<Self> {
+        Ok(ChannelOptions {
+            frame_size: config.frame_size(),
+            egress_capacity: config.egress_queue_capacity(),
+            fail_on_overload: config.fail_on_overload(),
+            metrics: Arc::new(Metrics::new()),
+            #[cfg(feature = "tls")]
+            tls: config.tls().map(Connector::new).transpose()?.map(Arc::new),
+        })
+    }
+
+    pub(in crate::remote) fn metrics(&self) -> WriteMetrics {
+        self.metrics.snapshot()
+    }
+
+    #[cfg(feature = "tls")]
+    async fn secure(&self, stream: TcpStream, host: &str) -> Result<Connection> {
+        match &self.tls {
+            Some(connector) => Ok(Box::new(connector.connect(host, stream).await?)),
+            None => Ok(Box::new(stream)),
+        }
+    }
+
+    #[cfg(not(feature = "tls"))]
+    async fn secure(&self, stream: TcpStream, _host: &str) -> Result<Connection> {
+        Ok(Box::new(stream))
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub struct WriteMetrics {
+    batches: u64,
+    messages: u64,
+    max_batch_size: u64,
+}
+
+impl WriteMetrics {
+    pub fn batches(&self) -> u64 {
+        self.batches
+    }
+
+    pub fn messages(&self) -> u64 {
+        self.messages
+    }
+
+    pub fn max_batch_size(&self) -> u64 {
+        self.max_batch_size
+    }
+
+    pub fn average_batch_size(&self) -> f64 {
+        if self.batches == 0 {
+            0.0
+        } else {
+            self.messages as f64 / self.batches as f64
+        }
+    }
+}
+
+struct Metrics {
+    batches: AtomicU64,
+    messages: AtomicU64,
+    max_batch_size: AtomicU64,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        Metrics {
+            batches: AtomicU64::new(0),
+            messages: AtomicU64::new(0),
+            max_batch_size: AtomicU64::new(0),
+        }
+    }
+
+    fn record(&self, batch_size: usize) {
+        let batch_size = batch_size.try_into().expect("unable to convert!");
+        self.batches.fetch_add(1, Ordering::Relaxed);
+        self.messages.fetch_add(batch_size, Ordering::Relaxed);
+        self.max_batch_size.fetch_max(batch_size, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> WriteMetrics {
+        WriteMetrics {
+            batches: self.batches.load(Ordering::Relaxed),
+            messages: self.messages.load(Ordering::Relaxed),
+            max_batch_size: self.max_batch_size.load(Ordering::Relaxed),
+        }
+    }
 }
 
 pub(in crate::remote) struct Channel {
-    egress: mpsc::UnboundedSender<(Message, Responder)>,
+    egress: mpsc::Sender<(Message, Responder, Option<Listener>)>,
+    unsubscribes: mpsc::UnboundedSender<u64>,
+    fail_on_overload: bool,
+    activity: Arc<Activity>,
+    closer: Mutex<Option<oneshot::Sender<Closing>>>,
+    closed: Mutex<Option<oneshot::Receiver<String>>>,
+    task: Mutex<Option<JoinHandle<Result<()>>>>,
 }
 
 impl Channel {
-    pub(in crate::remote) async fn connect(address: &SocketAddr) -> Result<Self> {
-        use std::collections::HashMap;
-        use tokio::{net::TcpStream, stream::StreamExt};
+    pub(in crate::remote) async fn connect(address: &SocketAddr, host: &str, options: &ChannelOptions) -> Result<Self> {
+        use tokio::stream::StreamExt;
 
-        let mut stream = TcpStream::connect(address).await?;
+        let mut stream = options.secure(TcpStream::connect(address).await?, host).await?;
         stream.write_all(&PROTOCOL_SEQUENCE).await?;
 
-        let (sender, receiver) = mpsc::unbounded_channel();
-        tokio::spawn(async move {
-            let (reader, writer) = stream.split();
+        let (sender, receiver) = mpsc::channel(options.egress_capacity);
+        let (unsubscribes, unsubscribed) = mpsc::unbounded_channel();
+        let (closer, close) = oneshot::channel();
+        let (notifier, closed) = oneshot::channel();
+        let activity = Arc::new(Activity::new());
+        let channel_activity = activity.clone();
+        let address = *address;
+        let fail_on_overload = options.fail_on_overload;
+        let options = options.clone();
+        let task = tokio::spawn(async move {
+            let (reader, writer) = tokio_io::split(stream);
             let mut writer = Writer::new(writer);
-            let mut events = Events::new(receiver, reader);
+            let mut events = Events::new(receiver, unsubscribed, reader, close);
+            let mut assembler = Assembler::new();
 
-            let mut correlations = HashMap::with_capacity(1024);
-            while let Some(event) = events.next().await {
+            let mut correlations: HashMap<u64, Responder> = HashMap::with_capacity(1024);
+            let mut listeners: HashMap<u64, Listener> = HashMap::new();
+            let mut closing = false;
+            let cause = 'events: loop {
+                let event = match events.next().await {
+                    Some(Ok(event)) => event,
+                    Some(Err(e)) => break Some(e.to_string()),
+                    None => break None,
+                };
                 match event {
-                    Ok(Event::Egress((message, responder))) => {
-                        writer.write(message.payload()).await?;
-                        correlations.insert(message.id(), responder);
+                    Event::Egress(egress) => {
+                        let mut batch_size = 0;
+                        let mut next = Some(egress);
+                        while let Some((message, responder, listener)) = next.take() {
+                            if closing {
+                                let _ = responder.send(Err(ClientShutdown));
+                            } else {
+                                match fragment(message.payload(), options.frame_size) {
+                                    Ok(fragments) => {
+                                        correlations.insert(message.id(), responder);
+                                        if let Some(listener) = listener {
+                                            listeners.insert(message.id(), listener);
+                                        }
+                                        for fragment in fragments {
+                                            if let Err(e) = writer.feed(fragment).await {
+                                                break 'events Some(e.to_string());
+                                            }
+                                        }
+                                        batch_size += 1;
+                                    }
+                                    Err(e) => {
+                                        let _ = responder.send(Err(e.into()));
+                                    }
+                                }
+                            }
+                            if batch_size < options.egress_capacity {
+                                next = events.try_next_egress();
+                            }
+                        }
+
+                        if batch_size > 0 {
+                            if let Err(e) = writer.flush().await {
+                                break 'events Some(e.to_string());
+                            }
+                            channel_activity.wrote();
+                            options.metrics.record(batch_size);
+                        }
                     }
-                    Ok(Event::Ingress(mut frame)) => {
-                        let message: Message = frame.to_bytes().into();
-                        match correlations
-                            .remove(&message.id())
-                            .expect("missing correlation!")
-                            .send(message)
-                        {
-                            _ => {} // TODO:
+                    Event::Ingress(mut frame) => {
+                        channel_activity.read();
+                        let assembled = assembler.assemble(frame.to_bytes());
+                        let message: Message =
+                            match assembled.and_then(|frame| frame.map(TryInto::try_into).transpose()) {
+                                Ok(Some(message)) => message,
+                                Ok(None) => continue,
+                                Err(e) => break Some(e.to_string()),
+                            };
+                        let id = message.id();
+                        if message.is_event() {
+                            match listeners.get(&id) {
+                                Some(listener) => {
+                                    if listener.send(message).is_err() {
+                                        listeners.remove(&id);
+                                    }
+                                }
+                                None => debug!("Received event with unknown correlation id {} from {}.", id, address),
+                            }
+                        } else {
+                            match correlations.remove(&id) {
+                                Some(responder) => {
+                                    let _ = responder.send(Ok(message));
+                                }
+                                None => warn!("Received response with unknown correlation id {} from {}.", id, address),
+                            }
                         }
                     }
-                    Err(e) => return Err(e),
+                    Event::Unsubscribe(id) => {
+                        listeners.remove(&id);
+                    }
+                    Event::Close => {
+                        closing = true;
+                        listeners.clear();
+                        assembler.retain(|correlation_id| correlations.contains_key(&correlation_id));
+                    }
+                    Event::Expire => {
+                        for (_, responder) in correlations.drain() {
+                            let _ = responder.send(Err(ClientShutdown));
+                        }
+                    }
+                    Event::Prune => correlations.retain(|_, responder| !responder.is_closed()),
+                }
+
+                if closing {
+                    correlations.retain(|_, responder| !responder.is_closed());
+                }
+                if closing && correlations.is_empty() {
+                    break None;
                 }
+            };
+
+            match cause {
+                Some(cause) => {
+                    warn!("Connection to {} closed - {}", address, cause);
+                    for (_, responder) in correlations.drain() {
+                        let _ = responder.send(Err(ConnectionClosed(cause.clone())));
+                    }
+                    let _ = notifier.send(cause);
+                    Ok(())
+                }
+                None => writer.close().await,
             }
-            Ok(())
         });
 
-        Ok(Channel { egress: sender })
+        Ok(Channel {
+            egress: sender,
+            unsubscribes,
+            fail_on_overload,
+            activity,
+            closer: Mutex::new(Some(closer)),
+            closed: Mutex::new(Some(closed)),
+            task: Mutex::new(Some(task)),
+        })
+    }
+
+    pub(in crate::remote) async fn close(&self, deadline: Instant) {
+        if let Some(closer) = self.closer.lock().expect("poisoned lock!").take() {
+            let _ = closer.send(Closing::Graceful(deadline));
+        }
+        let task = self.task.lock().expect("poisoned lock!").take();
+        if let Some(task) = task {
+            let _ = task.await;
+        }
+    }
+
+    pub(in crate::remote) fn abort(&self, cause: &str) {
+        if let Some(closer) = self.closer.lock().expect("poisoned lock!").take() {
+            let _ = closer.send(Closing::Abort(cause.to_string()));
+        }
+    }
+
+    pub(in crate::remote) fn closed(&self) -> Option<oneshot::Receiver<String>> {
+        self.closed.lock().expect("poisoned lock!").take()
+    }
+
+    pub(in crate::remote) fn last_read(&self) -> Instant {
+        self.activity.last_read()
+    }
+
+    pub(in crate::remote) fn last_write(&self) -> Instant {
+        self.activity.last_write()
     }
 
-    pub(in crate::remote) async fn send(&self, message: Message) -> Result<Message> {
+    pub(in crate::remote) async fn send(&self, message: Message) -> crate::Result<Message> {
         let (sender, receiver) = oneshot::channel();
-        self.egress.send((message, sender))?;
-        Ok(receiver.await?)
+        self.enqueue((message, sender, None)).await?;
+        match receiver.await {
+            Ok(result) => result,
+            Err(e) => Err(CommunicationFailure(Box::new(e))),
+        }
+    }
+
+    pub(in crate::remote) async fn subscribe(
+        &self,
+        message: Message,
+    ) -> crate::Result<(Message, mpsc::UnboundedReceiver<Message>)> {
+        let (sender, receiver) = oneshot::channel();
+        let (listener, events) = mpsc::unbounded_channel();
+        self.enqueue((message, sender, Some(listener))).await?;
+        match receiver.await {
+            Ok(result) => result.map(|message| (message, events)),
+            Err(e) => Err(CommunicationFailure(Box::new(e))),
+        }
+    }
+
+    pub(in crate::remote) fn unsubscribe(&self, correlation_id: u64) {
+        let _ = self.unsubscribes.send(correlation_id);
+    }
+
+    async fn enqueue(&self, egress: (Message, Responder, Option<Listener>)) -> crate::Result<()> {
+        let mut sender = self.egress.clone();
+        if self.fail_on_overload {
+            match sender.try_send(egress) {
+                Ok(()) => Ok(()),
+                Err(mpsc::error::TrySendError::Full(_)) => Err(Overloaded),
+                Err(e) => Err(CommunicationFailure(Box::new(e))),
+            }
+        } else {
+            sender.send(egress).await.map_err(|e| CommunicationFailure(Box::new(e)))
+        }
+    }
+}
+
+struct Activity {
+    origin: Instant,
+    last_read: AtomicU64,
+    last_write: AtomicU64,
+}
+
+impl Activity {
+    fn new() -> Self {
+        Activity {
+            origin: Instant::now(),
+            last_read: AtomicU64::new(0),
+            last_write: AtomicU64::new(0),
+        }
+    }
+
+    fn read(&self) {
+        self.last_read.store(self.elapsed(), Ordering::Relaxed);
+    }
+
+    fn wrote(&self) {
+        self.last_write.store(self.elapsed(), Ordering::Relaxed);
+    }
+
+    fn last_read(&self) -> Instant {
+        self.origin + Duration::from_millis(self.last_read.load(Ordering::Relaxed))
+    }
+
+    fn last_write(&self) -> Instant {
+        self.origin + Duration::from_millis(self.last_write.load(Ordering::Relaxed))
+    }
+
+    fn elapsed(&self) -> u64 {
+        self.origin
+            .elapsed()
+            .as_millis()
+            .try_into()
+            .expect("unable to convert!")
     }
 }
 
-struct Writer<'a> {
-    writer: FramedWrite<WriteHalf<'a>, LengthDelimitedCodec>,
+struct Writer {
+    writer: FramedWrite<WriteHalf<Connection>, LengthDelimitedCodec>,
 }
 
-impl<'a> Writer<'a> {
-    fn new(writer: WriteHalf<'a>) -> Self {
+impl Writer {
+    fn new(writer: WriteHalf<Connection>) -> Self {
         let writer = LengthDelimitedCodec::builder()
             .length_field_offset(LENGTH_FIELD_OFFSET)
             .length_field_length(LENGTH_FIELD_LENGTH)
             .length_adjustment(LENGTH_FIELD_ADJUSTMENT)
+            .max_frame_length(MAX_FRAME_LENGTH)
             .little_endian()
             .new_write(writer);
 
         Writer { writer }
     }
 
-    async fn write(&mut self, frame: Bytes) -> Result<()> {
+    async fn feed(&mut self, frame: Bytes) -> Result<()> {
+        use futures::SinkExt;
+
+        Ok(self.writer.feed(frame).await?)
+    }
+
+    async fn flush(&mut self) -> Result<()> {
         use futures::SinkExt;
 
-        Ok(self.writer.send(frame).await?)
+        Ok(self.writer.flush().await?)
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        use futures::SinkExt;
+
+        Ok(self.writer.close().await?)
     }
 }
 
-struct Events<'a> {
-    egress: mpsc::UnboundedReceiver<(Message, Responder)>,
-    ingress: FramedRead<ReadHalf<'a>, LengthDelimitedCodec>,
+struct Events {
+    egress: mpsc::Receiver<(Message, Responder, Option<Listener>)>,
+    unsubscribes: mpsc::UnboundedReceiver<u64>,
+    ingress: FramedRead<ReadHalf<Connection>, LengthDelimitedCodec>,
+    close: Option<oneshot::Receiver<Closing>>,
+    deadline: Option<Delay>,
+    prune: Interval,
 }
 
-impl<'a> Events<'a> {
-    fn new(messages: mpsc::UnboundedReceiver<(Message, Responder)>, reader: ReadHalf<'a>) -> Self {
+impl Events {
+    fn new(
+        messages: mpsc::Receiver<(Message, Responder, Option<Listener>)>,
+        unsubscribes: mpsc::UnboundedReceiver<u64>,
+        reader: ReadHalf<Connection>,
+        close: oneshot::Receiver<Closing>,
+    ) -> Self {
         let reader = LengthDelimitedCodec::builder()
             .length_field_offset(LENGTH_FIELD_OFFSET)
             .length_field_length(LENGTH_FIELD_LENGTH)
             .length_adjustment(LENGTH_FIELD_ADJUSTMENT)
+            .max_frame_length(MAX_FRAME_LENGTH)
             .little_endian()
             .new_read(reader);
 
         Events {
             egress: messages,
+            unsubscribes,
             ingress: reader,
+            close: Some(close),
+            deadline: None,
+            prune: time::interval_at((Instant::now() + PRUNE_INTERVAL).into(), PRUNE_INTERVAL),
         }
     }
 }
 
-impl Stream for Events<'_> {
+impl Events {
+    fn try_next_egress(&mut self) -> Option<(Message, Responder, Option<Listener>)> {
+        self.egress.try_recv().ok()
+    }
+}
+
+impl Stream for Events {
     type Item = Result<Event>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(close) = self.close.as_mut() {
+            match Pin::new(close).poll(cx) {
+                Poll::Ready(Ok(Closing::Graceful(deadline))) => {
+                    self.close = None;
+                    self.deadline = Some(time::delay_until(deadline.into()));
+                    return Poll::Ready(Some(Ok(Event::Close)));
+                }
+                Poll::Ready(Ok(Closing::Abort(cause))) => {
+                    self.close = None;
+                    return Poll::Ready(Some(Err(cause.into())));
+                }
+                Poll::Ready(Err(_)) => self.close = None,
+                Poll::Pending => {}
+            }
+        }
+        if let Some(deadline) = self.deadline.as_mut() {
+            if Pin::new(deadline).poll(cx).is_ready() {
+                self.deadline = None;
+                return Poll::Ready(Some(Ok(Event::Expire)));
+            }
+        }
+        if self.prune.poll_tick(cx).is_ready() {
+            return Poll::Ready(Some(Ok(Event::Prune)));
+        }
+
+        if let Poll::Ready(Some(id)) = Pin::new(&mut self.unsubscribes).poll_next(cx) {
+            return Poll::Ready(Some(Ok(Event::Unsubscribe(id))));
+        }
+
         match Pin::new(&mut self.egress).poll_next(cx) {
             Poll::Ready(Some(payload)) => return Poll::Ready(Some(Ok(Event::Egress(payload)))),
             Poll::Ready(None) => return Poll::Ready(None),
@@ -132,7 +550,465 @@ impl Stream for Events<'_> {
         Poll::Ready(match futures::ready!(Pin::new(&mut self.ingress).poll_next(cx)) {
             Some(Ok(frame)) => Some(Ok(Event::Ingress(frame))),
             Some(Err(e)) => Some(Err(e.into())),
-            None => None,
+            None => Some(Err(CLOSED_BY_PEER.into())),
         })
     }
 }
+
+fn fragment(mut frame: Bytes, frame_size: usize) -> codec::Result<Vec<Bytes>> {
+    use crate::codec::Writer;
+
+    if frame.len() + LENGTH_FIELD_LENGTH <= frame_size {
+        return Ok(vec![frame]);
+    }
+
+    let header = Header::decode(&frame)?;
+    let flags = header.flags & !UNFRAGMENTED_MESSAGE;
+
+    let mut payload = frame.split_off(header.payload_offset());
+    let chunk_size = frame_size.saturating_sub(HEADER_LENGTH).max(1);
+    let data_offset: u16 = HEADER_LENGTH.try_into().expect("unable to convert");
+
+    let mut fragments = Vec::with_capacity(payload.len() / chunk_size + 1);
+    while !payload.is_empty() {
+        let chunk = payload.split_to(chunk_size.min(payload.len()));
+
+        let mut fragment_flags = flags;
+        if fragments.is_empty() {
+            fragment_flags |= BEGIN_MESSAGE;
+        }
+        if payload.is_empty() {
+            fragment_flags |= END_MESSAGE;
+        }
+
+        let mut fragment = BytesMut::with_capacity(HEADER_LENGTH - LENGTH_FIELD_LENGTH + chunk.len());
+        header.version.write_to(&mut fragment);
+        fragment_flags.write_to(&mut fragment);
+        header.message_type.write_to(&mut fragment);
+        header.correlation_id.write_to(&mut fragment);
+        header.partition_id.write_to(&mut fragment);
+        data_offset.write_to(&mut fragment);
+        fragment.extend_from_slice(&chunk);
+        fragments.push(fragment.freeze());
+    }
+    Ok(fragments)
+}
+
+pub(in crate::remote) struct Assembler {
+    fragments: HashMap<u64, (u64, BytesMut)>,
+    sequence: u64,
+}
+
+impl Assembler {
+    pub(in crate::remote) fn new() -> Self {
+        Assembler {
+            fragments: HashMap::new(),
+            sequence: 0,
+        }
+    }
+
+    pub(in crate::remote) fn assemble(&mut self, frame: Bytes) -> codec::Result<Option<Bytes>> {
+        let header = Header::decode(&frame)?;
+        let correlation_id = header.correlation_id;
+
+        if header.flags & UNFRAGMENTED_MESSAGE == UNFRAGMENTED_MESSAGE {
+            return Ok(Some(frame));
+        }
+        if header.flags & BEGIN_MESSAGE != 0 {
+            if self.fragments.len() >= MAX_PARTIAL_MESSAGES && !self.fragments.contains_key(&correlation_id) {
+                self.evict();
+            }
+            self.sequence += 1;
+            self.fragments
+                .insert(correlation_id, (self.sequence, BytesMut::from(&frame[..])));
+            return Ok(None);
+        }
+
+        match self.fragments.get_mut(&correlation_id) {
+            Some((_, fragments)) => fragments.extend_from_slice(&frame[header.payload_offset()..]),
+            None => {
+                warn!("Dropping fragment of unknown message {}.", correlation_id);
+                return Ok(None);
+            }
+        }
+        if header.flags & END_MESSAGE == 0 {
+            return Ok(None);
+        }
+
+        Ok(self.fragments.remove(&correlation_id).map(|(_, mut message)| {
+            message[FLAGS_FIELD_OFFSET] |= END_MESSAGE;
+            message.freeze()
+        }))
+    }
+
+    pub(in crate::remote) fn retain<F: Fn(u64) -> bool>(&mut self, retained: F) {
+        self.fragments.retain(|correlation_id, _| retained(*correlation_id));
+    }
+
+    fn evict(&mut self) {
+        let oldest = self
+            .fragments
+            .iter()
+            .min_by_key(|(_, (sequence, _))| *sequence)
+            .map(|(correlation_id, _)| *correlation_id);
+        if let Some(correlation_id) = oldest {
+            warn!("Dropping incomplete message {}.", correlation_id);
+            self.fragments.remove(&correlation_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::remote::LISTENER_EVENT_FLAG;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_fail_pending_requests_on_close() {
+        use tokio::net::TcpListener;
+
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (_socket, _) = listener.accept().await.unwrap();
+            time::delay_for(Duration::from_secs(5)).await;
+        });
+
+        let channel = Arc::new(Channel::connect(&address, "127.0.0.1", &options()).await.unwrap());
+        let pending = {
+            let channel = channel.clone();
+            tokio::spawn(async move { channel.send(message(1)).await })
+        };
+        time::delay_for(Duration::from_millis(10)).await;
+        channel.close(Instant::now() + Duration::from_millis(50)).await;
+
+        assert!(matches!(pending.await.unwrap(), Err(ClientShutdown)));
+        assert!(matches!(channel.send(message(2)).await, Err(CommunicationFailure(_))));
+    }
+
+    #[tokio::test]
+    async fn should_fail_pending_requests_when_connection_dies() {
+        use tokio::net::TcpListener;
+
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let request = frame(1, UNFRAGMENTED_MESSAGE, &[1]);
+            let mut received = vec![0u8; PROTOCOL_SEQUENCE.len() + LENGTH_FIELD_LENGTH + request.len()];
+            socket.read_exact(&mut received).await.unwrap();
+
+            let response = frame(42, UNFRAGMENTED_MESSAGE, &[1]);
+            let length = (LENGTH_FIELD_LENGTH + response.len()) as u32;
+            socket.write_all(&length.to_le_bytes()).await.unwrap();
+            socket.write_all(&response).await.unwrap();
+        });
+
+        let channel = Channel::connect(&address, "127.0.0.1", &options()).await.unwrap();
+        let closed = channel.closed().unwrap();
+
+        assert!(matches!(channel.send(message(1)).await, Err(ConnectionClosed(cause)) if cause == CLOSED_BY_PEER));
+        assert_eq!(closed.await.unwrap(), CLOSED_BY_PEER);
+        assert!(channel.closed().is_none());
+    }
+
+    #[tokio::test]
+    async fn should_route_events_to_subscriber() {
+        use tokio::net::TcpListener;
+
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let request = frame(1, UNFRAGMENTED_MESSAGE, &[1]);
+            let mut received = vec![0u8; PROTOCOL_SEQUENCE.len() + LENGTH_FIELD_LENGTH + request.len()];
+            socket.read_exact(&mut received).await.unwrap();
+
+            for (correlation_id, flags, payload) in &[
+                (1, UNFRAGMENTED_MESSAGE, 1u8),
+                (1, UNFRAGMENTED_MESSAGE | LISTENER_EVENT_FLAG, 2u8),
+                (7, UNFRAGMENTED_MESSAGE | LISTENER_EVENT_FLAG, 3u8),
+                (1, UNFRAGMENTED_MESSAGE | LISTENER_EVENT_FLAG, 4u8),
+            ] {
+                let frame = frame(*correlation_id, *flags, &[*payload]);
+                let length = (LENGTH_FIELD_LENGTH + frame.len()) as u32;
+                socket.write_all(&length.to_le_bytes()).await.unwrap();
+                socket.write_all(&frame).await.unwrap();
+            }
+            time::delay_for(Duration::from_secs(5)).await;
+        });
+
+        let channel = Channel::connect(&address, "127.0.0.1", &options()).await.unwrap();
+        let (response, mut events) = channel.subscribe(message(1)).await.unwrap();
+
+        assert!(!response.is_event());
+        assert_eq!(response.payload().as_ref(), &[1]);
+        assert_eq!(events.recv().await.unwrap().payload().as_ref(), &[2]);
+        assert_eq!(events.recv().await.unwrap().payload().as_ref(), &[4]);
+    }
+
+    #[tokio::test]
+    async fn should_drop_subscriber_on_unsubscribe() {
+        use tokio::net::TcpListener;
+
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let request = frame(1, UNFRAGMENTED_MESSAGE, &[1]);
+            let mut received = vec![0u8; PROTOCOL_SEQUENCE.len() + LENGTH_FIELD_LENGTH + request.len()];
+            socket.read_exact(&mut received).await.unwrap();
+
+            let frame = frame(1, UNFRAGMENTED_MESSAGE, &[1]);
+            let length = (LENGTH_FIELD_LENGTH + frame.len()) as u32;
+            socket.write_all(&length.to_le_bytes()).await.unwrap();
+            socket.write_all(&frame).await.unwrap();
+            time::delay_for(Duration::from_secs(5)).await;
+        });
+
+        let channel = Channel::connect(&address, "127.0.0.1", &options()).await.unwrap();
+        let (_, mut events) = channel.subscribe(message(1)).await.unwrap();
+        channel.unsubscribe(1);
+
+        let event = time::timeout(Duration::from_secs(1), events.recv()).await.unwrap();
+        assert!(event.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_coalesce_queued_messages() {
+        use tokio::net::TcpListener;
+
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (received, receipt) = oneshot::channel();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let request = frame(1, UNFRAGMENTED_MESSAGE, &[1]);
+            let mut buffer = vec![0u8; PROTOCOL_SEQUENCE.len() + 5 * (LENGTH_FIELD_LENGTH + request.len())];
+            socket.read_exact(&mut buffer).await.unwrap();
+            let _ = received.send(());
+            time::delay_for(Duration::from_secs(5)).await;
+        });
+
+        let options = options();
+        let channel = Channel::connect(&address, "127.0.0.1", &options).await.unwrap();
+        for id in 0..5 {
+            let (responder, _) = oneshot::channel();
+            channel.enqueue((message(id), responder, None)).await.unwrap();
+        }
+        receipt.await.unwrap();
+        time::delay_for(Duration::from_millis(10)).await;
+
+        let metrics = options.metrics();
+        assert_eq!(metrics.batches(), 1);
+        assert_eq!(metrics.messages(), 5);
+        assert_eq!(metrics.max_batch_size(), 5);
+    }
+
+    #[test]
+    fn should_record_write_metrics() {
+        let metrics = Metrics::new();
+        assert_eq!(metrics.snapshot(), WriteMetrics::default());
+        assert_eq!(metrics.snapshot().average_batch_size(), 0.0);
+
+        metrics.record(1);
+        metrics.record(3);
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.batches(), 2);
+        assert_eq!(snapshot.messages(), 4);
+        assert_eq!(snapshot.max_batch_size(), 3);
+        assert_eq!(snapshot.average_batch_size(), 2.0);
+    }
+
+    #[tokio::test]
+    async fn should_fail_fast_when_egress_queue_is_full() {
+        let (channel, _receiver) = unconnected(true);
+
+        let (responder, _) = oneshot::channel();
+        assert!(channel.enqueue((message(1), responder, None)).await.is_ok());
+        let (responder, _) = oneshot::channel();
+        assert!(matches!(
+            channel.enqueue((message(2), responder, None)).await,
+            Err(Overloaded)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_wait_for_egress_queue_capacity() {
+        let (channel, mut receiver) = unconnected(false);
+
+        let (responder, _) = oneshot::channel();
+        assert!(channel.enqueue((message(1), responder, None)).await.is_ok());
+        let (responder, _) = oneshot::channel();
+        let blocked = channel.enqueue((message(2), responder, None));
+        tokio::pin!(blocked);
+        assert!(time::timeout(Duration::from_millis(10), &mut blocked).await.is_err());
+
+        receiver.recv().await.unwrap();
+        assert!(blocked.await.is_ok());
+    }
+
+    #[test]
+    fn should_track_activity() {
+        let activity = Activity::new();
+        assert_eq!(activity.last_read(), activity.origin);
+        assert_eq!(activity.last_write(), activity.origin);
+
+        std::thread::sleep(Duration::from_millis(5));
+        activity.read();
+        assert!(activity.last_read() > activity.origin);
+        assert_eq!(activity.last_write(), activity.origin);
+
+        activity.wrote();
+        assert!(activity.last_write() > activity.origin);
+    }
+
+    #[test]
+    fn should_not_fragment_small_frame() {
+        let frame = frame(1, UNFRAGMENTED_MESSAGE, &[1, 2, 3]);
+
+        assert_eq!(fragment(frame.clone(), 1024).unwrap(), vec![frame]);
+    }
+
+    #[test]
+    fn should_fragment_large_frame() {
+        let fragments = fragment(frame(1, UNFRAGMENTED_MESSAGE, &[1, 2, 3, 4, 5]), HEADER_LENGTH + 2).unwrap();
+
+        assert_eq!(
+            fragments,
+            vec![
+                frame(1, BEGIN_MESSAGE, &[1, 2]),
+                frame(1, 0, &[3, 4]),
+                frame(1, END_MESSAGE, &[5]),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_fail_to_fragment_malformed_frame() {
+        assert!(fragment(Bytes::from(vec![0; 8]), 4).is_err());
+    }
+
+    #[test]
+    fn should_assemble_interleaved_fragments() {
+        let mut assembler = Assembler::new();
+        let first = fragment(frame(1, UNFRAGMENTED_MESSAGE, &[1, 2, 3]), HEADER_LENGTH + 2).unwrap();
+        let second = fragment(frame(2, UNFRAGMENTED_MESSAGE, &[4, 5, 6]), HEADER_LENGTH + 2).unwrap();
+
+        assert_eq!(assembler.assemble(first[0].clone()).unwrap(), None);
+        assert_eq!(assembler.assemble(second[0].clone()).unwrap(), None);
+        assert_eq!(
+            assembler.assemble(second[1].clone()).unwrap(),
+            Some(frame(2, UNFRAGMENTED_MESSAGE, &[4, 5, 6]))
+        );
+        assert_eq!(
+            assembler.assemble(first[1].clone()).unwrap(),
+            Some(frame(1, UNFRAGMENTED_MESSAGE, &[1, 2, 3]))
+        );
+        assert!(assembler.fragments.is_empty());
+    }
+
+    #[test]
+    fn should_assemble_event_fragments() {
+        let mut assembler = Assembler::new();
+        let flags = UNFRAGMENTED_MESSAGE | LISTENER_EVENT_FLAG;
+
+        let mut assembled = None;
+        for fragment in fragment(frame(1, flags, &[1, 2, 3]), HEADER_LENGTH + 1).unwrap() {
+            assembled = assembler.assemble(fragment).unwrap();
+        }
+        assert_eq!(assembled, Some(frame(1, flags, &[1, 2, 3])));
+    }
+
+    #[test]
+    fn should_evict_oldest_incomplete_message() {
+        let mut assembler = Assembler::new();
+        for correlation_id in 0..=MAX_PARTIAL_MESSAGES as u64 {
+            assert_eq!(
+                assembler.assemble(frame(correlation_id, BEGIN_MESSAGE, &[1])).unwrap(),
+                None
+            );
+        }
+
+        assert_eq!(assembler.fragments.len(), MAX_PARTIAL_MESSAGES);
+        assert!(!assembler.fragments.contains_key(&0));
+        assert_eq!(assembler.assemble(frame(0, END_MESSAGE, &[2])).unwrap(), None);
+    }
+
+    #[test]
+    fn should_drop_incomplete_messages_on_retain() {
+        let mut assembler = Assembler::new();
+        assembler.assemble(frame(1, BEGIN_MESSAGE, &[1])).unwrap();
+        assembler.assemble(frame(2, BEGIN_MESSAGE, &[2])).unwrap();
+
+        assembler.retain(|correlation_id| correlation_id == 2);
+
+        assert_eq!(assembler.assemble(frame(1, END_MESSAGE, &[3])).unwrap(), None);
+        assert_eq!(
+            assembler.assemble(frame(2, END_MESSAGE, &[4])).unwrap(),
+            Some(frame(2, UNFRAGMENTED_MESSAGE, &[2, 4]))
+        );
+    }
+
+    #[test]
+    fn should_pass_through_unfragmented_frame() {
+        let frame = frame(1, UNFRAGMENTED_MESSAGE, &[1]);
+
+        assert_eq!(Assembler::new().assemble(frame.clone()).unwrap(), Some(frame));
+    }
+
+    #[test]
+    fn should_drop_fragment_of_unknown_message() {
+        assert_eq!(Assembler::new().assemble(frame(1, END_MESSAGE, &[1])).unwrap(), None);
+    }
+
+    fn options() -> ChannelOptions {
+        ChannelOptions {
+            frame_size: 1024,
+            egress_capacity: 16,
+            fail_on_overload: false,
+            metrics: Arc::new(Metrics::new()),
+            #[cfg(feature = "tls")]
+            tls: None,
+        }
+    }
+
+    fn unconnected(fail_on_overload: bool) -> (Channel, mpsc::Receiver<(Message, Responder, Option<Listener>)>) {
+        let (egress, receiver) = mpsc::channel(1);
+        let channel = Channel {
+            egress,
+            unsubscribes: mpsc::unbounded_channel().0,
+            fail_on_overload,
+            activity: Arc::new(Activity::new()),
+            closer: Mutex::new(None),
+            closed: Mutex::new(None),
+            task: Mutex::new(None),
+        };
+        (channel, receiver)
+    }
+
+    fn message(correlation_id: u64) -> Message {
+        Message(
+            correlation_id,
+            0x69,
+            UNFRAGMENTED_MESSAGE,
+            frame(correlation_id, UNFRAGMENTED_MESSAGE, &[1]),
+        )
+    }
+
+    fn frame(correlation_id: u64, flags: u8, payload: &[u8]) -> Bytes {
+        use crate::codec::Writer;
+
+        let mut frame = BytesMut::new();
+        1u8.write_to(&mut frame);
+        flags.write_to(&mut frame);
+        0x69u16.write_to(&mut frame);
+        correlation_id.write_to(&mut frame);
+        (-1i32).write_to(&mut frame);
+        22u16.write_to(&mut frame);
+        frame.extend_from_slice(payload);
+        frame.freeze()
+    }
+}
@@ -1,59 +1,134 @@
 use std::{
     collections::{HashMap, HashSet},
+    future::Future,
     hash::Hash,
     net::SocketAddr,
     pin::Pin,
     sync::{
-        atomic::{AtomicUsize, Ordering},
-        Arc,
+        atomic::{AtomicU32, AtomicUsize, Ordering},
+        Arc, Mutex,
     },
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
-use log::{error, info};
+use log::{error, info, warn};
 use tokio::{
     stream::{Stream, StreamExt},
-    sync::{oneshot, RwLock},
-    time::Interval,
+    sync::{broadcast, mpsc, oneshot, RwLock, Semaphore, SemaphorePermit},
+    time::{self, Interval},
 };
 
 use crate::{
-    messaging::{Address, Request, Response},
-    remote::member::Member,
-    HazelcastClientError::{ClusterNonOperational, NodeNonOperational},
-    Result,
+    config::{ClientConfig, RetryPolicy},
+    messaging::{Address, ClusterMember, Request, Response},
+    remote::{
+        channel::{ChannelOptions, WriteMetrics},
+        lifecycle::{Lifecycle, LifecycleState},
+        listener::{Listener, ListenerCodec, Listeners},
+        member::{Credentials, Member, Principal},
+        membership::{Membership, MembershipEvent},
+        Message,
+    },
+    serialization::Data,
+    HazelcastClientError::{
+        ClientShutdown, ClusterNonOperational, CommunicationFailure, ConnectionClosed, InvocationTimeout,
+        NodeNonOperational, Overloaded,
+    },
+    Result, TryFrom,
 };
 
 pub(crate) struct Cluster {
+    invocation_timeout: Duration,
+    shutdown_timeout: Duration,
+    invocations: Semaphore,
+    fail_on_overload: bool,
+    redo_operation: bool,
+    retry_policy: RetryPolicy,
+    lifecycle: Arc<Lifecycle>,
     members: Arc<Members>,
-    _pinger: Pinger,
+    membership: Arc<Membership>,
+    partitions: Arc<Partitions>,
+    listeners: Arc<Listeners>,
+    tasks: Mutex<Option<Tasks>>,
+}
+
+struct Tasks {
+    _heartbeat: Heartbeat,
+    _refresher: Refresher,
+    _reconnector: Reconnector,
+    _watcher: Watcher,
+    _observer: Observer,
+    _resubscriber: Resubscriber,
 }
 
 impl Cluster {
-    pub(crate) async fn init<E>(endpoints: E, username: &str, password: &str) -> Result<Self>
-    where
-        E: IntoIterator<Item = SocketAddr>,
-    {
-        let members = Arc::new(Members::from(endpoints, username, password).await?);
-        let pinger = Pinger::ping(members.clone());
-        // TODO: reconnector...
+    pub(crate) async fn init(config: &ClientConfig) -> Result<Self> {
+        let lifecycle = Arc::new(Lifecycle::new());
+        let members = Members::from(config).await?;
+        let partitions = Arc::new(Partitions::from(&members).await?);
+        let heartbeat = Heartbeat::start(members.clone(), config.heartbeat_interval(), config.heartbeat_timeout());
+        let refresher = Refresher::refresh(members.clone(), partitions.clone());
+        let reconnector = Reconnector::reconnect(members.clone(), config.retry_policy().clone());
+        let membership = Arc::new(Membership::new());
+        let watcher = Watcher::watch(members.clone(), membership.clone());
+        let observer = Observer::observe(members.clone(), lifecycle.clone()).await;
+        let (listeners, lost) = Listeners::new();
+        let listeners = Arc::new(listeners);
+        let resubscriber =
+            Resubscriber::resubscribe(members.clone(), listeners.clone(), lost, config.invocation_timeout());
 
         Ok(Cluster {
+            invocation_timeout: config.invocation_timeout(),
+            shutdown_timeout: config.shutdown_timeout(),
+            invocations: Semaphore::new(config.max_concurrent_invocations()),
+            fail_on_overload: config.fail_on_overload(),
+            redo_operation: config.redo_operation(),
+            retry_policy: config.retry_policy().clone(),
+            lifecycle,
             members,
-            _pinger: pinger,
+            membership,
+            partitions,
+            listeners,
+            tasks: Mutex::new(Some(Tasks {
+                _heartbeat: heartbeat,
+                _refresher: refresher,
+                _reconnector: reconnector,
+                _watcher: watcher,
+                _observer: observer,
+                _resubscriber: resubscriber,
+            })),
         })
     }
 
+    pub(crate) async fn shutdown(&self) {
+        if !self.lifecycle.transition(LifecycleState::ShuttingDown).await {
+            return;
+        }
+
+        let tasks = self.tasks.lock().expect("poisoned lock!").take();
+        drop(tasks);
+        self.listeners.clear();
+        self.members.close(Instant::now() + self.shutdown_timeout).await;
+
+        self.lifecycle.transition(LifecycleState::Shutdown).await;
+    }
+
     pub(crate) async fn dispatch<RQ, RS>(&self, request: RQ) -> Result<RS>
     where
         RQ: Request,
         RS: Response,
     {
-        match self.members.get().await {
-            Some(member) => member.send(request).await,
-            None => Err(ClusterNonOperational),
-        }
+        self.invoke(&request, None, Some(self.invocation_timeout)).await
+    }
+
+    pub(crate) async fn dispatch_blocking<RQ, RS>(&self, request: RQ, wait: Option<Duration>) -> Result<RS>
+    where
+        RQ: Request,
+        RS: Response,
+    {
+        let timeout = wait.map(|wait| self.invocation_timeout + wait);
+        self.invoke(&request, None, timeout).await
     }
 
     pub(crate) async fn forward<RQ, RS>(&self, request: RQ, address: &Address) -> Result<RS>
@@ -61,9 +136,150 @@ impl Cluster {
         RQ: Request,
         RS: Response,
     {
-        match self.members.get_by(address).await {
-            Some(member) => member.send(request).await,
-            None => Err(NodeNonOperational),
+        self.invoke(&request, Some(address), Some(self.invocation_timeout))
+            .await
+    }
+
+    pub(crate) async fn add_listener<C, H>(&self, codec: C, handler: H) -> Result<String>
+    where
+        C: ListenerCodec,
+        H: Fn(C::Event) + Send + Sync + 'static,
+    {
+        let registrar = Arc::new(Listener::new(codec, handler));
+        let redo = self.redo_operation || C::AddRequest::is_retryable();
+        self.execute(-1, None, Some(self.invocation_timeout), redo, |member| {
+            let registrar = registrar.clone();
+            async move { self.listeners.add(registrar, &member).await }
+        })
+        .await
+    }
+
+    pub(crate) async fn remove_listener(&self, id: &str) -> Result<bool> {
+        let registration = match self.listeners.remove(id) {
+            Some(registration) => registration,
+            None => return Ok(false),
+        };
+
+        let registered = self.members.get_by(registration.address()).await;
+        let address = self.address(Some(registration.address().clone())).await;
+        let redo = self.redo_operation || registration.is_deregister_retryable();
+        let removed = match address {
+            Ok(address) => {
+                self.execute(-1, Some(&address), Some(self.invocation_timeout), redo, |member| {
+                    let registration = &registration;
+                    async move { Ok((registration.deregister(&member).await?, member)) }
+                })
+                .await
+            }
+            Err(e) => Err(e),
+        };
+        let id = registration.registration_id().to_string();
+        registration.close(registered.as_deref());
+
+        let (removed, member) = removed?;
+        info!("Removed listener {} from {}.", id, member);
+        Ok(removed)
+    }
+
+    async fn invoke<RQ, RS>(&self, request: &RQ, address: Option<&Address>, timeout: Option<Duration>) -> Result<RS>
+    where
+        RQ: Request,
+        RS: Response,
+    {
+        let redo = self.redo_operation || RQ::is_retryable();
+        self.execute(request.partition_id(), address, timeout, redo, |member| async move {
+            member.send(request).await
+        })
+        .await
+    }
+
+    async fn execute<T, F, O>(
+        &self,
+        partition_id: i32,
+        address: Option<&Address>,
+        timeout: Option<Duration>,
+        redo: bool,
+        operation: F,
+    ) -> Result<T>
+    where
+        F: Fn(Arc<Member>) -> O,
+        O: Future<Output = Result<T>>,
+    {
+        let deadline = timeout.map(Deadline::after);
+        let _permit = self.acquire(deadline).await?;
+        let mut backoff = Backoff::from(&self.retry_policy);
+        loop {
+            if let LifecycleState::ShuttingDown | LifecycleState::Shutdown = self.lifecycle.state() {
+                return Err(ClientShutdown);
+            }
+
+            let result = match self.target(partition_id, address).await {
+                Ok(member) => {
+                    let sent = match deadline {
+                        Some(deadline) => deadline.limit(operation(member.clone())).await,
+                        None => operation(member.clone()).await,
+                    };
+                    match sent {
+                        Err(CommunicationFailure(e)) => {
+                            error!("Communication with {} failed - {}", member, e);
+                            self.members.disable(&member).await;
+                            Err(CommunicationFailure(e))
+                        }
+                        Err(ConnectionClosed(cause)) => {
+                            self.members.disable(&member).await;
+                            Err(ConnectionClosed(cause))
+                        }
+                        result => result,
+                    }
+                }
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Err(e)
+                    if e.is_retryable()
+                        && (redo || !e.is_indeterminate())
+                        && match deadline {
+                            Some(deadline) => backoff.current < deadline.remaining(),
+                            None => true,
+                        } =>
+                {
+                    warn!("Invocation failed, retrying - {}", e);
+                    time::delay_for(backoff.delay()).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn acquire(&self, deadline: Option<Deadline>) -> Result<SemaphorePermit<'_>> {
+        if self.fail_on_overload {
+            return self.invocations.try_acquire().map_err(|_| Overloaded);
+        }
+
+        match deadline {
+            Some(deadline) => deadline.limit(async { Ok(self.invocations.acquire().await) }).await,
+            None => Ok(self.invocations.acquire().await),
+        }
+    }
+
+    async fn target(&self, partition_id: i32, address: Option<&Address>) -> Result<Arc<Member>> {
+        if let Some(address) = address {
+            return self.members.get_by(address).await.ok_or(NodeNonOperational);
+        }
+
+        let owner = match partition_id {
+            _ if !self.members.smart_routing => None,
+            -1 => None,
+            partition_id => match self.partitions.owner(partition_id).await {
+                Some(address) => self.members.get_by(&address).await,
+                None => None,
+            },
+        };
+
+        match owner {
+            Some(owner) => Ok(owner),
+            None => self.members.get().await.ok_or(ClusterNonOperational),
         }
     }
 
@@ -80,6 +296,52 @@ impl Cluster {
         }
     }
 
+    pub(crate) fn lifecycle_state(&self) -> LifecycleState {
+        self.lifecycle.state()
+    }
+
+    pub(crate) fn lifecycle_states(&self) -> impl Stream<Item = LifecycleState> {
+        self.lifecycle.states()
+    }
+
+    pub(crate) async fn add_lifecycle_listener<L>(&self, listener: L) -> u64
+    where
+        L: Fn(&LifecycleState) + Send + Sync + 'static,
+    {
+        self.lifecycle.add_listener(Box::new(listener)).await
+    }
+
+    pub(crate) async fn remove_lifecycle_listener(&self, id: u64) -> bool {
+        self.lifecycle.remove_listener(id).await
+    }
+
+    pub(crate) fn connection_events(&self) -> impl Stream<Item = ConnectionEvent> {
+        self.members.events.subscribe().filter_map(|event| event.ok())
+    }
+
+    pub(crate) fn write_metrics(&self) -> WriteMetrics {
+        self.members.channel_options.metrics()
+    }
+
+    pub(crate) async fn members(&self) -> Vec<ClusterMember> {
+        self.membership.members().await
+    }
+
+    pub(crate) async fn add_membership_listener<L>(&self, listener: L) -> u64
+    where
+        L: Fn(&MembershipEvent) + Send + Sync + 'static,
+    {
+        self.membership.add_listener(Box::new(listener)).await
+    }
+
+    pub(crate) async fn remove_membership_listener(&self, id: u64) -> bool {
+        self.membership.remove_listener(id).await
+    }
+
+    pub(crate) fn partition_id(&self, key: &Data) -> i32 {
+        self.partitions.id(key)
+    }
+
     pub(crate) async fn to_string(&self) -> String {
         let members = self.members.get_all().await;
 
@@ -93,59 +355,549 @@ impl Cluster {
     }
 }
 
-const PING_INTERVAL: Duration = Duration::from_secs(300);
+#[derive(Copy, Clone)]
+struct Deadline {
+    instant: Instant,
+    timeout: Duration,
+}
+
+impl Deadline {
+    fn after(timeout: Duration) -> Self {
+        Deadline {
+            instant: Instant::now() + timeout,
+            timeout,
+        }
+    }
+
+    fn remaining(&self) -> Duration {
+        self.instant.saturating_duration_since(Instant::now())
+    }
+
+    async fn limit<T>(&self, future: impl Future<Output = Result<T>>) -> Result<T> {
+        time::timeout(self.remaining(), future)
+            .await
+            .unwrap_or(Err(InvocationTimeout(self.timeout)))
+    }
+}
+
+const HEARTBEAT_TIMED_OUT: &str = "heartbeat timed out";
 
-struct Pinger {
+struct Heartbeat {
     _handle: oneshot::Sender<()>,
 }
 
-impl Pinger {
-    fn ping(members: Arc<Members>) -> Self {
+impl Heartbeat {
+    fn start(members: Arc<Members>, interval: Duration, timeout: Duration) -> Self {
         use crate::messaging::ping::{PingRequest, PingResponse};
 
         let (handle, receiver) = oneshot::channel();
         tokio::spawn(async move {
-            let mut ticks = Ticks::new(PING_INTERVAL, receiver);
-            while let Some(_) = ticks.next().await {
+            let mut ticks = Ticks::new(interval, receiver);
+            while ticks.next().await.is_some() {
+                let now = Instant::now();
                 for member in members.get_all().await {
-                    if let Err(_) = member.send::<PingRequest, PingResponse>(PingRequest::new()).await {
-                        error!("Pinging {} failed.", member);
-                        members.disable(&*member).await
+                    if now.saturating_duration_since(member.last_read()) >= timeout {
+                        error!("Heartbeat to {} timed out.", member);
+                        member.abort(HEARTBEAT_TIMED_OUT);
+                        members.disable(&member).await;
+                    } else if now.saturating_duration_since(member.last_write()) >= interval {
+                        let members = members.clone();
+                        tokio::spawn(async move {
+                            let request = PingRequest::new();
+                            let ping = member.send::<_, PingResponse>(&request);
+                            if let Ok(Err(e)) = time::timeout(timeout, ping).await {
+                                error!("Pinging {} failed - {}", member, e);
+                                member.abort(&e.to_string());
+                                members.disable(&member).await;
+                            }
+                        });
+                    }
+                }
+            }
+        });
+
+        Heartbeat { _handle: handle }
+    }
+}
+
+struct Observer {
+    _handle: oneshot::Sender<()>,
+}
+
+impl Observer {
+    async fn observe(members: Arc<Members>, lifecycle: Arc<Lifecycle>) -> Self {
+        let mut events = members.events.subscribe();
+        Observer::update(&members, &lifecycle).await;
+
+        let (handle, mut receiver) = oneshot::channel::<()>();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = &mut receiver => return,
+                    event = events.recv() => match event {
+                        Err(broadcast::RecvError::Closed) => return,
+                        _ => Observer::update(&members, &lifecycle).await,
+                    }
+                }
+            }
+        });
+
+        Observer { _handle: handle }
+    }
+
+    async fn update(members: &Members, lifecycle: &Lifecycle) {
+        let state = match members.get().await {
+            Some(_) => LifecycleState::Connected,
+            None => LifecycleState::Disconnected,
+        };
+        lifecycle.transition(state).await;
+    }
+}
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+struct Refresher {
+    _handle: oneshot::Sender<()>,
+}
+
+impl Refresher {
+    fn refresh(members: Arc<Members>, partitions: Arc<Partitions>) -> Self {
+        let (handle, receiver) = oneshot::channel();
+        tokio::spawn(async move {
+            let mut ticks = Ticks::new(REFRESH_INTERVAL, receiver);
+            while ticks.next().await.is_some() {
+                if let Err(e) = partitions.refresh(&members).await {
+                    error!("Refreshing partitions failed - {}", e);
+                }
+            }
+        });
+
+        Refresher { _handle: handle }
+    }
+}
+
+struct Partitions {
+    count: AtomicU32,
+    owners: RwLock<HashMap<u32, Address>>,
+}
+
+impl Partitions {
+    async fn from(members: &Members) -> Result<Self> {
+        let owners = Partitions::fetch(members).await?;
+
+        Ok(Partitions {
+            count: AtomicU32::new(owners.len() as u32),
+            owners: RwLock::new(owners),
+        })
+    }
+
+    async fn refresh(&self, members: &Members) -> Result<()> {
+        let owners = Partitions::fetch(members).await?;
+        let mut current = self.owners.write().await;
+        self.count.store(owners.len() as u32, Ordering::Relaxed);
+        *current = owners;
+        Ok(())
+    }
+
+    async fn fetch(members: &Members) -> Result<HashMap<u32, Address>> {
+        use crate::messaging::partition::{GetPartitionsRequest, GetPartitionsResponse};
+
+        let response: GetPartitionsResponse = match members.get().await {
+            Some(member) => member.send(&GetPartitionsRequest::new()).await?,
+            None => return Err(ClusterNonOperational),
+        };
+
+        Ok(response
+            .partitions()
+            .iter()
+            .flat_map(|entry| entry.ids().iter().map(move |id| (*id, entry.address().clone())))
+            .collect())
+    }
+
+    async fn owner(&self, partition_id: i32) -> Option<Address> {
+        self.owners.read().await.get(&(partition_id as u32)).cloned()
+    }
+
+    fn id(&self, key: &Data) -> i32 {
+        hash_to_index(key.partition_hash(), self.count.load(Ordering::Relaxed))
+    }
+}
+
+fn hash_to_index(hash: i32, count: u32) -> i32 {
+    match (hash, count) {
+        (_, 0) => -1,
+        (i32::MIN, _) => 0,
+        (hash, count) => hash.abs() % count as i32,
+    }
+}
+
+struct Watcher {
+    _handle: oneshot::Sender<()>,
+}
+
+impl Watcher {
+    fn watch(members: Arc<Members>, membership: Arc<Membership>) -> Self {
+        use crate::messaging::membership::{AddMembershipListenerRequest, AddMembershipListenerResponse};
+
+        let (handle, receiver) = oneshot::channel();
+        tokio::spawn(async move {
+            let mut ticks = Ticks::new(RECONNECT_INTERVAL, receiver);
+            while ticks.next().await.is_some() {
+                let owner = match members.get_owner().await {
+                    Some(owner) => owner,
+                    None => continue,
+                };
+                let request = AddMembershipListenerRequest::new(false);
+                let mut events = match owner.subscribe::<_, AddMembershipListenerResponse>(&request).await {
+                    Ok((_, response, events)) => {
+                        info!("Registered membership listener {} on {}.", response.id(), owner);
+                        events
+                    }
+                    Err(e) => {
+                        error!("Failed to register membership listener on {} - {}", owner, e);
+                        continue;
+                    }
+                };
+                drop(owner);
+
+                loop {
+                    tokio::select! {
+                        tick = ticks.next() => if tick.is_none() {
+                            return;
+                        },
+                        event = events.next() => match event {
+                            Some(event) => Watcher::handle(event, &members, &membership).await,
+                            None => break,
+                        }
+                    }
+                }
+            }
+        });
+
+        Watcher { _handle: handle }
+    }
+
+    async fn handle(event: Message, members: &Arc<Members>, membership: &Membership) {
+        use crate::messaging::membership::{
+            MemberAttributeChangeEvent, MemberEvent, MemberListEvent, MEMBER_ADDED, MEMBER_REMOVED,
+        };
+
+        let r#type = event.r#type();
+        let changes = if r#type == MemberListEvent::r#type() {
+            match TryFrom::<MemberListEvent>::try_from(event) {
+                Ok(event) => membership.reset(event.members()).await,
+                Err(e) => return error!("Failed to decode member list event - {}", e),
+            }
+        } else if r#type == MemberEvent::r#type() {
+            match TryFrom::<MemberEvent>::try_from(event) {
+                Ok(event) if event.event_type() == MEMBER_ADDED => membership.add(event.member()).await,
+                Ok(event) if event.event_type() == MEMBER_REMOVED => membership.remove(event.member()).await,
+                Ok(event) => return error!("Unknown member event type - {}", event.event_type()),
+                Err(e) => return error!("Failed to decode member event - {}", e),
+            }
+        } else if r#type == MemberAttributeChangeEvent::r#type() {
+            match TryFrom::<MemberAttributeChangeEvent>::try_from(event) {
+                Ok(event) => {
+                    membership
+                        .change_attribute(event.id(), event.key(), event.value())
+                        .await
+                }
+                Err(e) => return error!("Failed to decode member attribute change event - {}", e),
+            }
+        } else {
+            return error!("Unknown membership event type - {}", r#type);
+        };
+
+        for change in changes {
+            match change {
+                MembershipEvent::MemberAdded(member) => {
+                    info!("Member {} added.", member);
+                    if members.smart_routing && members.get_by(member.address()).await.is_none() {
+                        if let Err(e) = members.connect_to(member.address()).await {
+                            error!("Failed to connect to {} - {}", member, e);
+                            members.disable_by(member.address()).await;
+                        }
+                    }
+                }
+                MembershipEvent::MemberRemoved(member) => {
+                    info!("Member {} removed.", member);
+                    members.remove(member.address()).await;
+                }
+                MembershipEvent::MemberAttributeChanged { .. } => {}
+            }
+        }
+    }
+}
+
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(1);
+
+struct Reconnector {
+    _handle: oneshot::Sender<()>,
+}
+
+impl Reconnector {
+    fn reconnect(members: Arc<Members>, retry_policy: RetryPolicy) -> Self {
+        let (handle, receiver) = oneshot::channel();
+        tokio::spawn(async move {
+            let mut backoffs: HashMap<Address, Backoff> = HashMap::new();
+            let mut ticks = Ticks::new(RECONNECT_INTERVAL, receiver);
+            while ticks.next().await.is_some() {
+                let disabled = members.get_disabled().await;
+                backoffs.retain(|address, _| disabled.contains(address));
+                for address in disabled {
+                    let backoff = backoffs
+                        .entry(address.clone())
+                        .or_insert_with(|| Backoff::from(&retry_policy));
+                    if !backoff.is_due() {
+                        continue;
+                    }
+                    match members.reconnect(&address).await {
+                        Ok(()) => {
+                            info!("Reconnected to {}.", address);
+                            backoffs.remove(&address);
+                        }
+                        Err(e) => {
+                            error!("Failed to reconnect to {} - {}", address, e);
+                            backoff.delay();
+                        }
+                    }
+                }
+
+                if let Err(e) = members.elect().await {
+                    error!("Failed to elect owner member - {}", e);
+                }
+            }
+        });
+
+        Reconnector { _handle: handle }
+    }
+}
+
+struct Resubscriber {
+    _handle: oneshot::Sender<()>,
+}
+
+impl Resubscriber {
+    fn resubscribe(
+        members: Arc<Members>,
+        listeners: Arc<Listeners>,
+        mut lost: mpsc::UnboundedReceiver<String>,
+        timeout: Duration,
+    ) -> Self {
+        let (handle, receiver) = oneshot::channel();
+        tokio::spawn(async move {
+            let mut pending: HashSet<String> = HashSet::new();
+            let mut ticks = Ticks::new(RECONNECT_INTERVAL, receiver);
+            loop {
+                tokio::select! {
+                    tick = ticks.next() => if tick.is_none() {
+                        return;
+                    },
+                    id = lost.recv() => match id {
+                        Some(id) => {
+                            pending.insert(id);
+                        }
+                        None => return,
+                    }
+                }
+
+                for id in pending.clone() {
+                    let member = match members.get().await {
+                        Some(member) => member,
+                        None => break,
+                    };
+                    match time::timeout(timeout, listeners.restore(&id, &member)).await {
+                        Ok(Ok(())) => {
+                            pending.remove(&id);
+                        }
+                        Ok(Err(e)) => error!("Failed to re-register listener {} on {} - {}", id, member, e),
+                        Err(_) => error!("Failed to re-register listener {} on {} - timed out", id, member),
                     }
                 }
             }
         });
 
-        Pinger { _handle: handle }
+        Resubscriber { _handle: handle }
     }
 }
 
+struct Backoff {
+    current: Duration,
+    max: Duration,
+    multiplier: f64,
+    next_attempt: Instant,
+}
+
+impl Backoff {
+    fn new(initial: Duration, max: Duration, multiplier: f64) -> Self {
+        Backoff {
+            current: initial,
+            max,
+            multiplier,
+            next_attempt: Instant::now(),
+        }
+    }
+
+    fn is_due(&self) -> bool {
+        Instant::now() >= self.next_attempt
+    }
+
+    fn delay(&mut self) -> Duration {
+        let delay = self.current;
+        self.next_attempt = Instant::now() + delay;
+        self.current =
+            Duration::from_secs_f64((self.current.as_secs_f64() * self.multiplier).min(self.max.as_secs_f64()));
+        delay
+    }
+}
+
+impl From<&RetryPolicy> for Backoff {
+    fn from(retry_policy: &RetryPolicy) -> Self {
+        Backoff::new(
+            retry_policy.initial_backoff(),
+            retry_policy.max_backoff(),
+            retry_policy.multiplier(),
+        )
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ConnectionEvent {
+    Opened(Address),
+    Closed(Address),
+}
+
+const CONNECTION_EVENTS_CAPACITY: usize = 128;
+
 struct Members {
+    credentials: Credentials,
+    connection_timeout: Duration,
+    channel_options: ChannelOptions,
+    smart_routing: bool,
+    principal: RwLock<Option<Principal>>,
+    owner: RwLock<Option<Address>>,
     registry: RwLock<Registry<Address, Member>>,
+    events: broadcast::Sender<ConnectionEvent>,
 }
 
 impl Members {
-    async fn from<'a, E>(endpoints: E, username: &str, password: &str) -> Result<Self>
-    where
-        E: IntoIterator<Item = SocketAddr>,
-    {
-        let mut registry = Registry::new();
-        for endpoint in endpoints.into_iter().collect::<HashSet<SocketAddr>>() {
-            info!("Trying to connect to {} as owner member.", endpoint);
-            match Member::connect(&endpoint, username, password).await {
-                Ok(member) => registry.enable(member.address().clone(), member),
-                Err(e) => error!("Failed to connect to {} - {}", endpoint, e),
+    async fn from(config: &ClientConfig) -> Result<Arc<Self>> {
+        let members = Arc::new(Members {
+            credentials: Credentials::from(config),
+            connection_timeout: config.connection_timeout(),
+            channel_options: ChannelOptions::new(config)?,
+            smart_routing: config.smart_routing(),
+            principal: RwLock::new(None),
+            owner: RwLock::new(None),
+            registry: RwLock::new(Registry::new()),
+            events: broadcast::channel(CONNECTION_EVENTS_CAPACITY).0,
+        });
+        let mut endpoints = HashSet::new();
+        for endpoint in config.addresses().iter().filter(|endpoint| endpoints.insert(*endpoint)) {
+            match members.connect(endpoint, &endpoint.ip().to_string()).await {
+                Ok(_) if !members.smart_routing => break,
+                Ok(_) => {}
+                Err(e) => {
+                    error!("Failed to connect to {} - {}", endpoint, e);
+                    members.registry.write().await.disable_by(Address::from(endpoint));
+                }
             }
         }
 
-        Ok(Members {
-            registry: RwLock::new(registry),
-        })
+        Ok(members)
+    }
+
+    async fn connect(self: &Arc<Self>, endpoint: &SocketAddr, host: &str) -> Result<Address> {
+        let principal = self.principal.read().await.clone();
+        let owner = self.owner.read().await.is_none();
+        if owner {
+            info!("Trying to connect to {} as owner member.", endpoint);
+        } else {
+            info!("Trying to connect to {}.", endpoint);
+        }
+
+        let connection = Member::connect(
+            endpoint,
+            host,
+            &self.channel_options,
+            &self.credentials,
+            principal.as_ref(),
+            owner,
+        );
+        let (member, principal) = match time::timeout(self.connection_timeout, connection).await {
+            Ok(result) => result?,
+            Err(e) => return Err(CommunicationFailure(Box::new(e))),
+        };
+        let address = member.address().clone();
+        if owner {
+            *self.principal.write().await = Some(principal);
+            *self.owner.write().await = Some(address.clone());
+        }
+        let closed = member.closed();
+        let member = self.registry.write().await.enable(address.clone(), member);
+        let _ = self.events.send(ConnectionEvent::Opened(address.clone()));
+        if let Some(closed) = closed {
+            self.evict_on(closed, &member);
+        }
+        Ok(address)
+    }
+
+    fn evict_on(self: &Arc<Self>, closed: oneshot::Receiver<String>, member: &Arc<Member>) {
+        let members = Arc::downgrade(self);
+        let member = Arc::downgrade(member);
+        tokio::spawn(async move {
+            if closed.await.is_err() {
+                return;
+            }
+            if let (Some(members), Some(member)) = (members.upgrade(), member.upgrade()) {
+                let current = members.get_by(member.address()).await;
+                if matches!(current, Some(current) if Arc::ptr_eq(&current, &member)) {
+                    members.disable(&member).await;
+                }
+            }
+        });
     }
 
-    /*async fn enable(&self, address: Address, member: Member) {
-        self.registry.write().await.enable(address, member)
-    }*/
+    async fn connect_to(self: &Arc<Self>, address: &Address) -> Result<Address> {
+        let endpoint = match tokio::net::lookup_host(address.to_string()).await {
+            Ok(mut endpoints) => endpoints.next().ok_or(NodeNonOperational)?,
+            Err(e) => return Err(CommunicationFailure(Box::new(e))),
+        };
+
+        self.connect(&endpoint, address.host()).await
+    }
+
+    async fn reconnect(self: &Arc<Self>, address: &Address) -> Result<()> {
+        if self.connect_to(address).await? != *address {
+            self.registry.write().await.forget(address);
+        }
+        Ok(())
+    }
+
+    async fn elect(&self) -> Result<()> {
+        if self.owner.read().await.is_some() {
+            return Ok(());
+        }
+
+        let members = self.get_all().await;
+        if members.is_empty() {
+            return Ok(());
+        }
+
+        let principal = self.principal.read().await.clone();
+        for member in members {
+            info!("Trying to elect {} as owner member.", member);
+            match member.authenticate(&self.credentials, principal.as_ref(), true).await {
+                Ok(principal) => {
+                    *self.principal.write().await = Some(principal);
+                    *self.owner.write().await = Some(member.address().clone());
+                    return Ok(());
+                }
+                Err(e) => error!("Failed to elect {} as owner member - {}", member, e),
+            }
+        }
+        Err(ClusterNonOperational)
+    }
 
     async fn get(&self) -> Option<Arc<Member>> {
         self.registry.read().await.get()
@@ -159,8 +911,61 @@ impl Members {
         self.registry.read().await.get_all()
     }
 
+    async fn get_disabled(&self) -> Vec<Address> {
+        self.registry.read().await.get_disabled()
+    }
+
+    async fn get_owner(&self) -> Option<Arc<Member>> {
+        match self.owner.read().await.as_ref() {
+            Some(address) => self.get_by(address).await,
+            None => None,
+        }
+    }
+
     async fn disable(&self, member: &Member) {
-        self.registry.write().await.disable(member)
+        let enabled = {
+            let mut registry = self.registry.write().await;
+            let enabled = registry.get_by(member.address()).is_some();
+            registry.disable(member);
+            enabled
+        };
+        self.release(member.address(), enabled).await;
+    }
+
+    async fn disable_by(&self, address: &Address) {
+        let enabled = {
+            let mut registry = self.registry.write().await;
+            let enabled = registry.get_by(address).is_some();
+            registry.disable_by(address.clone());
+            enabled
+        };
+        self.release(address, enabled).await;
+    }
+
+    async fn release(&self, address: &Address, closed: bool) {
+        {
+            let mut owner = self.owner.write().await;
+            if owner.as_ref() == Some(address) {
+                *owner = None;
+            }
+        }
+        if closed {
+            info!("Connection to {} closed.", address);
+            let _ = self.events.send(ConnectionEvent::Closed(address.clone()));
+        }
+    }
+
+    async fn close(&self, deadline: Instant) {
+        let members = self.get_all().await;
+        futures::future::join_all(members.iter().map(|member| member.close(deadline))).await;
+        for member in members {
+            self.disable(&member).await;
+        }
+    }
+
+    async fn remove(&self, address: &Address) {
+        self.disable_by(address).await;
+        self.registry.write().await.forget(address);
     }
 }
 
@@ -185,11 +990,15 @@ where
         }
     }
 
-    fn enable(&mut self, key: K, value: V) {
+    fn enable(&mut self, key: K, value: V) -> Arc<V> {
         self.disabled.remove(&key);
+        if let Some(previous) = self.enabled_by_key.remove(&key) {
+            self.enabled.retain(|v| !Arc::ptr_eq(v, &previous));
+        }
         let value = Arc::new(value);
         self.enabled.push(value.clone());
-        self.enabled_by_key.insert(key, value);
+        self.enabled_by_key.insert(key, value.clone());
+        value
     }
 
     fn get(&self) -> Option<Arc<V>> {
@@ -209,6 +1018,10 @@ where
         self.enabled.iter().map(Arc::clone).collect()
     }
 
+    fn get_disabled(&self) -> Vec<K> {
+        self.disabled.iter().cloned().collect()
+    }
+
     fn disable(&mut self, value: &V) {
         self.enabled
             .iter()
@@ -221,9 +1034,19 @@ where
             .nth(0)
             .map(|(k, _)| k.clone())
         {
-            self.enabled_by_key.remove(&key);
-            self.disabled.insert(key);
+            self.disable_by(key);
+        }
+    }
+
+    fn disable_by(&mut self, key: K) {
+        if let Some(value) = self.enabled_by_key.remove(&key) {
+            self.enabled.retain(|v| !Arc::ptr_eq(v, &value));
         }
+        self.disabled.insert(key);
+    }
+
+    fn forget(&mut self, key: &K) {
+        self.disabled.remove(key);
     }
 }
 
@@ -261,8 +1084,47 @@ impl Stream for Ticks {
 
 #[cfg(test)]
 mod tests {
+    use crate::{
+        messaging::{
+            map::{MapPutRequest, MapPutResponse},
+            ping::{PingRequest, PingResponse},
+        },
+        remote::test_server::{payload, Reply, TestServer},
+        serialization::SerializationService,
+        HazelcastClientError::HazelcastInstanceNotActive,
+    };
+
     use super::*;
 
+    #[test]
+    fn should_convert_hash_to_index() {
+        assert_eq!(hash_to_index(0, 271), 0);
+        assert_eq!(hash_to_index(272, 271), 1);
+        assert_eq!(hash_to_index(-272, 271), 1);
+        assert_eq!(hash_to_index(i32::MIN, 271), 0);
+        assert_eq!(hash_to_index(1, 0), -1);
+    }
+
+    #[test]
+    fn should_back_off_exponentially() {
+        let (initial, max) = (Duration::from_secs(1), Duration::from_secs(30));
+        let mut backoff = Backoff::new(initial, max, 2.0);
+        assert!(backoff.is_due());
+
+        assert_eq!(backoff.delay(), initial);
+        assert!(!backoff.is_due());
+        assert_eq!(backoff.current, initial * 2);
+
+        for _ in 0..10 {
+            backoff.delay();
+        }
+        assert_eq!(backoff.current, max);
+
+        let mut backoff = Backoff::new(initial, max, 1.5);
+        backoff.delay();
+        assert_eq!(backoff.current, Duration::from_millis(1500));
+    }
+
     #[test]
     fn should_get_none_for_empty_registry() {
         let registry: Registry<&str, &str> = Registry::new();
@@ -300,4 +1162,249 @@ mod tests {
         assert!(registry.get_by(&key).is_none());
         assert!(registry.get_all().is_empty());
     }
+
+    #[test]
+    fn should_get_disabled_after_disable() {
+        let mut registry = Registry::new();
+
+        let key = "some-key";
+        let value = "some=value";
+
+        registry.enable(key, value);
+        registry.disable(&value);
+
+        assert_eq!(registry.get_disabled(), vec![key]);
+    }
+
+    #[test]
+    fn should_get_some_after_reenable() {
+        let mut registry = Registry::new();
+
+        let key = "some-key";
+        let value = "some=value";
+
+        registry.enable(key, value);
+        registry.disable(&value);
+        registry.enable(key, value);
+
+        assert_eq!(*registry.get().unwrap(), value);
+        assert_eq!(registry.get_all().len(), 1);
+        assert!(registry.get_disabled().is_empty());
+    }
+
+    #[test]
+    fn should_replace_on_enable_with_same_key() {
+        let mut registry = Registry::new();
+
+        let key = "some-key";
+
+        registry.enable(key, "some-value");
+        registry.enable(key, "other-value");
+
+        assert_eq!(*registry.get_by(&key).unwrap(), "other-value");
+        assert_eq!(registry.get_all().len(), 1);
+    }
+
+    #[test]
+    fn should_forget_disabled() {
+        let mut registry: Registry<&str, &str> = Registry::new();
+
+        let key = "some-key";
+
+        registry.disable_by(key);
+        registry.forget(&key);
+
+        assert!(registry.get_disabled().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_connect_and_dispatch() {
+        let server = TestServer::start().await.unwrap();
+        let cluster = Cluster::init(&config(&server).build()).await.unwrap();
+
+        let response: PingResponse = cluster.dispatch(PingRequest::new()).await.unwrap();
+
+        assert_eq!(response, PingResponse {});
+        assert_eq!(cluster.lifecycle_state(), LifecycleState::Connected);
+    }
+
+    #[tokio::test]
+    async fn should_time_out_unanswered_invocation() {
+        let server = TestServer::start().await.unwrap();
+        server.respond(PingRequest::r#type(), |_| vec![]);
+        let timeout = Duration::from_millis(100);
+        let cluster = Cluster::init(&config(&server).invocation_timeout(timeout).build())
+            .await
+            .unwrap();
+
+        match cluster.dispatch::<_, PingResponse>(PingRequest::new()).await {
+            Err(InvocationTimeout(duration)) => assert_eq!(duration, timeout),
+            _ => panic!("unexpected result"),
+        }
+
+        let wait = Duration::from_millis(50);
+        match cluster
+            .dispatch_blocking::<_, PingResponse>(PingRequest::new(), Some(wait))
+            .await
+        {
+            Err(InvocationTimeout(duration)) => assert_eq!(duration, timeout + wait),
+            _ => panic!("unexpected result"),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_shut_down_promptly_after_timed_out_invocation() {
+        let server = TestServer::start().await.unwrap();
+        server.respond(PingRequest::r#type(), |_| vec![]);
+        let cluster = Cluster::init(
+            &config(&server)
+                .invocation_timeout(Duration::from_millis(100))
+                .shutdown_timeout(Duration::from_secs(10))
+                .build(),
+        )
+        .await
+        .unwrap();
+        assert!(cluster.dispatch::<_, PingResponse>(PingRequest::new()).await.is_err());
+
+        let started = Instant::now();
+        cluster.shutdown().await;
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn should_close_connection_on_heartbeat_timeout() {
+        let server = TestServer::start().await.unwrap();
+        server.respond(PingRequest::r#type(), |_| vec![]);
+        server.respond(MapPutRequest::r#type(), |_| vec![]);
+        let cluster = Cluster::init(
+            &config(&server)
+                .heartbeat_interval(Duration::from_millis(50))
+                .heartbeat_timeout(Duration::from_millis(200))
+                .build(),
+        )
+        .await
+        .unwrap();
+
+        let data = SerializationService::default().to_data(&1);
+        let put = cluster.dispatch_blocking::<_, MapPutResponse>(MapPutRequest::new("map", &data, &data, 1, -1), None);
+
+        match time::timeout(Duration::from_secs(5), put).await {
+            Ok(Err(ConnectionClosed(cause))) => assert_eq!(cause, HEARTBEAT_TIMED_OUT),
+            _ => panic!("unexpected result"),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_update_partition_count_on_refresh() {
+        use crate::{messaging::partition::GetPartitionsRequest, remote::test_server::partitions};
+
+        let server = TestServer::start().await.unwrap();
+        let owners = vec![Address::from(&server.address())];
+        let attempts = AtomicUsize::new(0);
+        server.respond(GetPartitionsRequest::r#type(), move |_| {
+            match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 => vec![partitions(&[])],
+                _ => vec![partitions(&owners)],
+            }
+        });
+        let cluster = Cluster::init(&config(&server).build()).await.unwrap();
+        let key = SerializationService::default().to_data(&1);
+        assert_eq!(cluster.partition_id(&key), -1);
+
+        cluster.partitions.refresh(&cluster.members).await.unwrap();
+
+        assert_ne!(cluster.partition_id(&key), -1);
+    }
+
+    #[tokio::test]
+    async fn should_retry_retryable_failure() {
+        let server = TestServer::start().await.unwrap();
+        let attempts = AtomicUsize::new(0);
+        server.respond(PingRequest::r#type(), move |_| {
+            match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 => vec![Reply::exception(50, "com.hazelcast.spi.exception.RetryableIOException")],
+                _ => vec![Reply::response(PingResponse::r#type(), bytes::Bytes::new())],
+            }
+        });
+        let cluster = Cluster::init(&config(&server).build()).await.unwrap();
+
+        let response: PingResponse = cluster.dispatch(PingRequest::new()).await.unwrap();
+
+        assert_eq!(response, PingResponse {});
+        assert_eq!(server.received(PingRequest::r#type()), 2);
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_non_idempotent_request_on_indeterminate_failure() {
+        for redo_operation in &[false, true] {
+            let server = TestServer::start().await.unwrap();
+            let attempts = AtomicUsize::new(0);
+            server.respond(MapPutRequest::r#type(), move |_| {
+                match attempts.fetch_add(1, Ordering::SeqCst) {
+                    0 => vec![Reply::exception(
+                        21,
+                        "com.hazelcast.core.HazelcastInstanceNotActiveException",
+                    )],
+                    _ => vec![Reply::response(MapPutResponse::r#type(), payload(&[&None::<Data>]))],
+                }
+            });
+            let cluster = Cluster::init(&config(&server).redo_operation(*redo_operation).build())
+                .await
+                .unwrap();
+
+            let data = SerializationService::default().to_data(&1);
+            let result = cluster
+                .dispatch::<_, MapPutResponse>(MapPutRequest::new("map", &data, &data, 1, -1))
+                .await;
+
+            match redo_operation {
+                true => assert!(result.is_ok()),
+                false => assert!(matches!(result, Err(HazelcastInstanceNotActive(_)))),
+            }
+            assert_eq!(
+                server.received(MapPutRequest::r#type()),
+                if *redo_operation { 2 } else { 1 }
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn should_reject_invocation_after_shutdown() {
+        let server = TestServer::start().await.unwrap();
+        let cluster = Cluster::init(&config(&server).build()).await.unwrap();
+
+        cluster.shutdown().await;
+
+        match cluster.dispatch::<_, PingResponse>(PingRequest::new()).await {
+            Err(ClientShutdown) => {}
+            _ => panic!("unexpected result"),
+        }
+        assert_eq!(cluster.lifecycle_state(), LifecycleState::Shutdown);
+    }
+
+    #[tokio::test]
+    async fn should_publish_closed_event_on_disconnect() {
+        let server = TestServer::start().await.unwrap();
+        let cluster = Cluster::init(&config(&server).build()).await.unwrap();
+        let mut events = cluster.connection_events();
+
+        server.disconnect();
+
+        let address = Address::from(&server.address());
+        match time::timeout(Duration::from_secs(5), events.next()).await {
+            Ok(Some(ConnectionEvent::Closed(closed))) => assert_eq!(closed, address),
+            _ => panic!("unexpected event"),
+        }
+    }
+
+    fn config(server: &TestServer) -> crate::config::ClientConfigBuilder {
+        ClientConfig::builder()
+            .address(server.address())
+            .retry_policy(RetryPolicy::new(
+                Duration::from_millis(100),
+                Duration::from_secs(1),
+                2.0,
+            ))
+    }
 }
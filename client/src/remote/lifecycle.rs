@@ -0,0 +1,142 @@
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use derive_more::Display;
+use log::info;
+use tokio::sync::{watch, RwLock};
+
+#[derive(Display, Eq, PartialEq, Debug, Copy, Clone)]
+pub enum LifecycleState {
+    #[display(fmt = "STARTING")]
+    Starting,
+    #[display(fmt = "CONNECTED")]
+    Connected,
+    #[display(fmt = "DISCONNECTED")]
+    Disconnected,
+    #[display(fmt = "SHUTTING_DOWN")]
+    ShuttingDown,
+    #[display(fmt = "SHUTDOWN")]
+    Shutdown,
+}
+
+type LifecycleListener = Box<dyn Fn(&LifecycleState) + Send + Sync>;
+
+pub(in crate::remote) struct Lifecycle {
+    sender: watch::Sender<LifecycleState>,
+    receiver: watch::Receiver<LifecycleState>,
+    listeners: RwLock<HashMap<u64, LifecycleListener>>,
+    sequencer: AtomicU64,
+}
+
+impl Lifecycle {
+    pub(in crate::remote) fn new() -> Self {
+        info!(
+            "HazelcastClient {} is {}",
+            env!("CARGO_PKG_VERSION"),
+            LifecycleState::Starting
+        );
+
+        let (sender, receiver) = watch::channel(LifecycleState::Starting);
+        Lifecycle {
+            sender,
+            receiver,
+            listeners: RwLock::new(HashMap::new()),
+            sequencer: AtomicU64::new(0),
+        }
+    }
+
+    pub(in crate::remote) fn state(&self) -> LifecycleState {
+        *self.receiver.borrow()
+    }
+
+    pub(in crate::remote) fn states(&self) -> watch::Receiver<LifecycleState> {
+        self.receiver.clone()
+    }
+
+    pub(in crate::remote) async fn add_listener(&self, listener: LifecycleListener) -> u64 {
+        let id = self.sequencer.fetch_add(1, Ordering::SeqCst);
+        self.listeners.write().await.insert(id, listener);
+        id
+    }
+
+    pub(in crate::remote) async fn remove_listener(&self, id: u64) -> bool {
+        self.listeners.write().await.remove(&id).is_some()
+    }
+
+    pub(in crate::remote) async fn transition(&self, state: LifecycleState) -> bool {
+        use LifecycleState::*;
+
+        let listeners = self.listeners.write().await;
+        let allowed = match (self.state(), state) {
+            (current, state) if current == state => false,
+            (ShuttingDown, Shutdown) => true,
+            (ShuttingDown, _) | (Shutdown, _) => false,
+            _ => true,
+        };
+        if !allowed {
+            return false;
+        }
+
+        info!("HazelcastClient is {}", state);
+        let _ = self.sender.broadcast(state);
+        for listener in listeners.values() {
+            listener(&state);
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_start_in_starting_state() {
+        assert_eq!(Lifecycle::new().state(), LifecycleState::Starting);
+    }
+
+    #[tokio::test]
+    async fn should_transition_between_connection_states() {
+        let lifecycle = Lifecycle::new();
+
+        assert!(lifecycle.transition(LifecycleState::Connected).await);
+        assert!(!lifecycle.transition(LifecycleState::Connected).await);
+        assert!(lifecycle.transition(LifecycleState::Disconnected).await);
+        assert_eq!(lifecycle.state(), LifecycleState::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn should_not_leave_shutting_down_except_to_shutdown() {
+        let lifecycle = Lifecycle::new();
+
+        assert!(lifecycle.transition(LifecycleState::ShuttingDown).await);
+        assert!(!lifecycle.transition(LifecycleState::Connected).await);
+        assert!(lifecycle.transition(LifecycleState::Shutdown).await);
+        assert!(!lifecycle.transition(LifecycleState::Disconnected).await);
+        assert_eq!(lifecycle.state(), LifecycleState::Shutdown);
+    }
+
+    #[tokio::test]
+    async fn should_notify_listeners_and_watchers() {
+        use tokio::stream::StreamExt;
+
+        let lifecycle = Lifecycle::new();
+        let states = Arc::new(Mutex::new(vec![]));
+        let mut watcher = lifecycle.states();
+
+        let captured = states.clone();
+        let id = lifecycle
+            .add_listener(Box::new(move |state| captured.lock().unwrap().push(*state)))
+            .await;
+        lifecycle.transition(LifecycleState::Connected).await;
+        assert!(lifecycle.remove_listener(id).await);
+        lifecycle.transition(LifecycleState::Disconnected).await;
+
+        assert_eq!(*states.lock().unwrap(), vec![LifecycleState::Connected]);
+        assert_eq!(watcher.next().await, Some(LifecycleState::Disconnected));
+    }
+}
@@ -0,0 +1,398 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use futures::future::BoxFuture;
+use log::{error, info};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{
+    messaging::{Address, Request, Response},
+    remote::{member::Member, Message},
+    Result, TryFrom,
+};
+
+pub(crate) trait ListenerCodec: Send + Sync + 'static {
+    type AddRequest: Request + Send + Sync;
+    type AddResponse: Response;
+    type RemoveRequest: Request + Send + Sync;
+    type RemoveResponse: Response;
+    type Event: Response + Send;
+
+    fn add_request(&self) -> Self::AddRequest;
+
+    fn registration_id(response: &Self::AddResponse) -> &str;
+
+    fn remove_request(&self, registration_id: &str) -> Self::RemoveRequest;
+
+    fn removed(response: &Self::RemoveResponse) -> bool;
+}
+
+type Subscription = (String, u64, mpsc::UnboundedReceiver<Message>);
+
+pub(in crate::remote) trait Registrar: Send + Sync {
+    fn register<'a>(&'a self, member: &'a Member) -> BoxFuture<'a, Result<Subscription>>;
+
+    fn dispatch(
+        &self,
+        events: mpsc::UnboundedReceiver<Message>,
+        on_close: Box<dyn FnOnce() + Send>,
+    ) -> oneshot::Sender<()>;
+
+    fn deregister<'a>(&'a self, member: &'a Member, registration_id: &'a str) -> BoxFuture<'a, Result<bool>>;
+
+    fn is_deregister_retryable(&self) -> bool;
+}
+
+pub(in crate::remote) struct Listener<C: ListenerCodec> {
+    codec: C,
+    handler: Arc<dyn Fn(C::Event) + Send + Sync>,
+}
+
+impl<C: ListenerCodec> Listener<C> {
+    pub(in crate::remote) fn new<H>(codec: C, handler: H) -> Self
+    where
+        H: Fn(C::Event) + Send + Sync + 'static,
+    {
+        Listener {
+            codec,
+            handler: Arc::new(handler),
+        }
+    }
+}
+
+impl<C: ListenerCodec> Registrar for Listener<C> {
+    fn register<'a>(&'a self, member: &'a Member) -> BoxFuture<'a, Result<Subscription>> {
+        Box::pin(async move {
+            let request = self.codec.add_request();
+            let (correlation_id, response, events) = member.subscribe::<_, C::AddResponse>(&request).await?;
+            Ok((C::registration_id(&response).to_string(), correlation_id, events))
+        })
+    }
+
+    fn dispatch(
+        &self,
+        mut events: mpsc::UnboundedReceiver<Message>,
+        on_close: Box<dyn FnOnce() + Send>,
+    ) -> oneshot::Sender<()> {
+        let handler = self.handler.clone();
+        let (handle, mut receiver) = oneshot::channel::<()>();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = &mut receiver => return,
+                    event = events.recv() => match event {
+                        Some(event) => match TryFrom::<C::Event>::try_from(event) {
+                            Ok(event) => handler(event),
+                            Err(e) => error!("Failed to decode event - {}", e),
+                        },
+                        None => return on_close(),
+                    }
+                }
+            }
+        });
+        handle
+    }
+
+    fn deregister<'a>(&'a self, member: &'a Member, registration_id: &'a str) -> BoxFuture<'a, Result<bool>> {
+        Box::pin(async move {
+            let request = self.codec.remove_request(registration_id);
+            let response: C::RemoveResponse = member.send(&request).await?;
+            Ok(C::removed(&response))
+        })
+    }
+
+    fn is_deregister_retryable(&self) -> bool {
+        C::RemoveRequest::is_retryable()
+    }
+}
+
+pub(in crate::remote) struct Registration {
+    registration_id: String,
+    correlation_id: u64,
+    address: Address,
+    registrar: Arc<dyn Registrar>,
+    handle: oneshot::Sender<()>,
+}
+
+impl Registration {
+    pub(in crate::remote) fn registration_id(&self) -> &str {
+        &self.registration_id
+    }
+
+    pub(in crate::remote) fn address(&self) -> &Address {
+        &self.address
+    }
+
+    pub(in crate::remote) async fn deregister(&self, member: &Member) -> Result<bool> {
+        self.registrar.deregister(member, &self.registration_id).await
+    }
+
+    pub(in crate::remote) fn is_deregister_retryable(&self) -> bool {
+        self.registrar.is_deregister_retryable()
+    }
+
+    pub(in crate::remote) fn close(self, member: Option<&Member>) {
+        if let Some(member) = member {
+            member.unsubscribe(self.correlation_id);
+        }
+        let _ = self.handle.send(());
+    }
+}
+
+pub(in crate::remote) struct Listeners {
+    registrations: Mutex<HashMap<String, Registration>>,
+    lost: mpsc::UnboundedSender<String>,
+}
+
+impl Listeners {
+    pub(in crate::remote) fn new() -> (Self, mpsc::UnboundedReceiver<String>) {
+        let (lost, receiver) = mpsc::unbounded_channel();
+        let listeners = Listeners {
+            registrations: Mutex::new(HashMap::new()),
+            lost,
+        };
+        (listeners, receiver)
+    }
+
+    pub(in crate::remote) async fn add(&self, registrar: Arc<dyn Registrar>, member: &Member) -> Result<String> {
+        let (registration_id, correlation_id, events) = registrar.register(member).await?;
+        let id = registration_id.clone();
+        let registration = Registration {
+            registration_id,
+            correlation_id,
+            address: member.address().clone(),
+            handle: registrar.dispatch(events, self.on_close(&id)),
+            registrar,
+        };
+        info!(
+            "Registered listener {} on {} with correlation id {}.",
+            id, member, registration.correlation_id
+        );
+        self.registrations
+            .lock()
+            .expect("poisoned lock!")
+            .insert(id.clone(), registration);
+        Ok(id)
+    }
+
+    pub(in crate::remote) async fn restore(&self, id: &str, member: &Member) -> Result<()> {
+        let registrar = match self.registrations.lock().expect("poisoned lock!").get(id) {
+            Some(registration) => registration.registrar.clone(),
+            None => return Ok(()),
+        };
+
+        let (registration_id, correlation_id, events) = registrar.register(member).await?;
+        let handle = registrar.dispatch(events, self.on_close(id));
+        let restored = match self.registrations.lock().expect("poisoned lock!").get_mut(id) {
+            Some(registration) => {
+                info!(
+                    "Re-registered listener {} as {} on {} with correlation id {}.",
+                    id, registration_id, member, correlation_id
+                );
+                registration.registration_id = registration_id.clone();
+                registration.correlation_id = correlation_id;
+                registration.address = member.address().clone();
+                registration.handle = handle;
+                true
+            }
+            None => false,
+        };
+        if !restored {
+            member.unsubscribe(correlation_id);
+            registrar.deregister(member, &registration_id).await?;
+        }
+        Ok(())
+    }
+
+    pub(in crate::remote) fn remove(&self, id: &str) -> Option<Registration> {
+        self.registrations.lock().expect("poisoned lock!").remove(id)
+    }
+
+    pub(in crate::remote) fn clear(&self) {
+        self.registrations.lock().expect("poisoned lock!").clear();
+    }
+
+    fn on_close(&self, id: &str) -> Box<dyn FnOnce() + Send> {
+        let (id, lost) = (id.to_string(), self.lost.clone());
+        Box::new(move || {
+            let _ = lost.send(id);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Cursor,
+        sync::atomic::{AtomicI64, Ordering},
+        time::Duration,
+    };
+
+    use tokio::time;
+
+    use crate::{
+        codec::Reader,
+        messaging::topic::{
+            TopicAddMessageListenerRequest, TopicAddMessageListenerResponse, TopicEvent,
+            TopicRemoveMessageListenerRequest, TopicRemoveMessageListenerResponse,
+        },
+        remote::{
+            cluster::Cluster,
+            test_server::{payload, Reply, TestServer},
+        },
+        serialization::{Data, SerializationService},
+        ClientConfig,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_dispatch_events_until_removed() {
+        let server = TestServer::start().await.unwrap();
+        server.respond(TopicAddMessageListenerRequest::r#type(), |_| {
+            vec![
+                Reply::response(TopicAddMessageListenerResponse::r#type(), payload(&[&"listener-id"])),
+                Reply::event(TopicEvent::r#type(), payload(&[&item(), &69i64, &"member-id"])),
+            ]
+        });
+        server.respond(TopicRemoveMessageListenerRequest::r#type(), |_| {
+            vec![Reply::response(
+                TopicRemoveMessageListenerResponse::r#type(),
+                payload(&[&true]),
+            )]
+        });
+        let cluster = Cluster::init(&ClientConfig::builder().address(server.address()).build())
+            .await
+            .unwrap();
+
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let codec = TopicListener("some-topic".to_string());
+        let id = cluster
+            .add_listener(codec, move |event: TopicEvent| {
+                let _ = sender.send(event.publish_time());
+            })
+            .await
+            .unwrap();
+        assert_eq!(id, "listener-id");
+
+        let received = time::timeout(Duration::from_secs(5), receiver.recv()).await.unwrap();
+        assert_eq!(received, Some(69));
+
+        assert!(cluster.remove_listener(&id).await.unwrap());
+        assert!(!cluster.remove_listener(&id).await.unwrap());
+        assert_eq!(server.received(TopicRemoveMessageListenerRequest::r#type()), 1);
+        assert_eq!(receiver.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn should_re_register_after_reconnect() {
+        let server = TestServer::start().await.unwrap();
+        let registrations = Arc::new(AtomicI64::new(0));
+        let deregistered = Arc::new(Mutex::new(vec![]));
+        server.respond(TopicAddMessageListenerRequest::r#type(), move |_| {
+            let registration = registrations.fetch_add(1, Ordering::SeqCst) + 1;
+            vec![
+                Reply::response(
+                    TopicAddMessageListenerResponse::r#type(),
+                    payload(&[&format!("listener-{}", registration)]),
+                ),
+                Reply::event(TopicEvent::r#type(), payload(&[&item(), &registration, &"member-id"])),
+            ]
+        });
+        let ids = deregistered.clone();
+        server.respond(TopicRemoveMessageListenerRequest::r#type(), move |request| {
+            let readable = &mut Cursor::new(request);
+            let (_, id) = (
+                String::read_from(readable).unwrap(),
+                String::read_from(readable).unwrap(),
+            );
+            ids.lock().unwrap().push(id);
+            vec![Reply::response(
+                TopicRemoveMessageListenerResponse::r#type(),
+                payload(&[&true]),
+            )]
+        });
+        let cluster = Cluster::init(&ClientConfig::builder().address(server.address()).build())
+            .await
+            .unwrap();
+
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let id = cluster
+            .add_listener(TopicListener("some-topic".to_string()), move |event: TopicEvent| {
+                let _ = sender.send(event.publish_time());
+            })
+            .await
+            .unwrap();
+        let received = time::timeout(Duration::from_secs(5), receiver.recv()).await.unwrap();
+        assert_eq!(received, Some(1));
+
+        server.disconnect();
+
+        let received = time::timeout(Duration::from_secs(10), receiver.recv()).await.unwrap();
+        assert_eq!(received, Some(2));
+        assert_eq!(id, "listener-1");
+        assert!(cluster.remove_listener(&id).await.unwrap());
+        assert_eq!(*deregistered.lock().unwrap(), vec!["listener-2".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn should_retry_failed_registration() {
+        let server = TestServer::start().await.unwrap();
+        let attempts = AtomicI64::new(0);
+        server.respond(TopicAddMessageListenerRequest::r#type(), move |_| {
+            match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 => vec![Reply::exception(
+                    41,
+                    "com.hazelcast.spi.exception.PartitionMigratingException",
+                )],
+                _ => vec![Reply::response(
+                    TopicAddMessageListenerResponse::r#type(),
+                    payload(&[&"listener-id"]),
+                )],
+            }
+        });
+        let cluster = Cluster::init(&ClientConfig::builder().address(server.address()).build())
+            .await
+            .unwrap();
+
+        let id = cluster
+            .add_listener(TopicListener("some-topic".to_string()), |_: TopicEvent| {})
+            .await
+            .unwrap();
+
+        assert_eq!(id, "listener-id");
+        assert_eq!(server.received(TopicAddMessageListenerRequest::r#type()), 2);
+    }
+
+    fn item() -> Data {
+        SerializationService::default().to_data(&"item".to_string())
+    }
+
+    struct TopicListener(String);
+
+    impl ListenerCodec for TopicListener {
+        type AddRequest = TopicAddMessageListenerRequest;
+        type AddResponse = TopicAddMessageListenerResponse;
+        type RemoveRequest = TopicRemoveMessageListenerRequest;
+        type RemoveResponse = TopicRemoveMessageListenerResponse;
+        type Event = TopicEvent;
+
+        fn add_request(&self) -> Self::AddRequest {
+            TopicAddMessageListenerRequest::new(&self.0, false)
+        }
+
+        fn registration_id(response: &Self::AddResponse) -> &str {
+            response.id()
+        }
+
+        fn remove_request(&self, registration_id: &str) -> Self::RemoveRequest {
+            TopicRemoveMessageListenerRequest::new(&self.0, registration_id)
+        }
+
+        fn removed(response: &Self::RemoveResponse) -> bool {
+            response.removed()
+        }
+    }
+}
@@ -1,64 +1,169 @@
 use std::{
     net::SocketAddr,
     sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
 };
 
 use derive_more::Display;
+use tokio::sync::{mpsc, oneshot};
 
 use crate::{
+    config::ClientConfig,
     messaging::{Address, Request, Response},
-    remote::{channel::Channel, CLIENT_TYPE, CLIENT_VERSION, PROTOCOL_VERSION},
+    remote::{
+        channel::{Channel, ChannelOptions},
+        Message, CLIENT_TYPE, CLIENT_VERSION, PROTOCOL_VERSION,
+    },
     HazelcastClientError::{AuthenticationFailure, CommunicationFailure},
     {Result, TryFrom},
 };
 
-#[derive(Display)]
-#[display(fmt = "{} - {:?}", address, owner_id)]
-pub(in crate::remote) struct Member {
+#[derive(Clone)]
+pub(in crate::remote) struct Credentials {
+    username: String,
+    password: String,
+    name: String,
+    labels: Vec<String>,
+}
+
+impl From<&ClientConfig> for Credentials {
+    fn from(config: &ClientConfig) -> Self {
+        Credentials {
+            username: config.group_name().to_string(),
+            password: config.group_password().to_string(),
+            name: config.name().to_string(),
+            labels: config.labels().to_vec(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(in crate::remote) struct Principal {
     id: String,
     owner_id: String,
+}
+
+#[derive(Display)]
+#[display(fmt = "{}", address)]
+pub(in crate::remote) struct Member {
     address: Address,
 
     sender: Sender,
 }
 
 impl Member {
-    pub(in crate::remote) async fn connect(endpoint: &SocketAddr, username: &str, password: &str) -> Result<Self> {
-        use crate::messaging::authentication::{AuthenticationRequest, AuthenticationResponse, AuthenticationStatus};
-
-        let channel = match Channel::connect(endpoint).await {
+    pub(in crate::remote) async fn connect(
+        endpoint: &SocketAddr,
+        host: &str,
+        options: &ChannelOptions,
+        credentials: &Credentials,
+        principal: Option<&Principal>,
+        owner: bool,
+    ) -> Result<(Self, Principal)> {
+        let channel = match Channel::connect(endpoint, host, options).await {
             Ok(channel) => channel,
             Err(e) => return Err(CommunicationFailure(e)),
         };
         let sender = Sender::new(channel);
 
-        let request = AuthenticationRequest::new(username, password, CLIENT_TYPE, PROTOCOL_VERSION, CLIENT_VERSION);
-        let response: AuthenticationResponse = sender.send(request).await?;
+        let (address, principal) = Member::authenticate_with(&sender, credentials, principal, owner).await?;
+        Ok((Member { address, sender }, principal))
+    }
+
+    pub(in crate::remote) async fn authenticate(
+        &self,
+        credentials: &Credentials,
+        principal: Option<&Principal>,
+        owner: bool,
+    ) -> Result<Principal> {
+        Member::authenticate_with(&self.sender, credentials, principal, owner)
+            .await
+            .map(|(_, principal)| principal)
+    }
+
+    async fn authenticate_with(
+        sender: &Sender,
+        credentials: &Credentials,
+        principal: Option<&Principal>,
+        owner: bool,
+    ) -> Result<(Address, Principal)> {
+        use crate::messaging::authentication::{AuthenticationRequest, AuthenticationResponse, AuthenticationStatus};
+
+        let mut request = AuthenticationRequest::new(
+            &credentials.username,
+            &credentials.password,
+            CLIENT_TYPE,
+            PROTOCOL_VERSION,
+            CLIENT_VERSION,
+        )
+        .owner_connection(owner)
+        .client_name(&credentials.name)
+        .labels(&credentials.labels);
+        if let Some(principal) = principal {
+            request = request.principal(&principal.id, &principal.owner_id);
+        }
+
+        let response: AuthenticationResponse = sender.send(&request).await?;
         match AuthenticationResponse::status(&response) {
-            AuthenticationStatus::Authenticated => Ok(Member {
-                id: response.id().as_ref().expect("missing id!").clone(),
-                owner_id: response.owner_id().as_ref().expect("missing owner id!").clone(),
-                address: response.address().as_ref().expect("missing address!").clone(),
-                sender,
-            }),
+            AuthenticationStatus::Authenticated => match (response.address(), response.id(), response.owner_id()) {
+                (Some(address), Some(id), Some(owner_id)) => Ok((
+                    address.clone(),
+                    Principal {
+                        id: id.clone(),
+                        owner_id: owner_id.clone(),
+                    },
+                )),
+                _ => Err(AuthenticationFailure("incomplete response".to_string())),
+            },
             status => Err(AuthenticationFailure(status.to_string())),
         }
     }
 
-    pub(in crate::remote) async fn send<RQ: Request, RS: Response>(&self, request: RQ) -> Result<RS> {
+    pub(in crate::remote) async fn send<RQ: Request, RS: Response>(&self, request: &RQ) -> Result<RS> {
         self.sender.send(request).await
     }
 
+    pub(in crate::remote) async fn subscribe<RQ: Request, RS: Response>(
+        &self,
+        request: &RQ,
+    ) -> Result<(u64, RS, mpsc::UnboundedReceiver<Message>)> {
+        self.sender.subscribe(request).await
+    }
+
+    pub(in crate::remote) fn unsubscribe(&self, correlation_id: u64) {
+        self.sender.channel.unsubscribe(correlation_id)
+    }
+
     pub(in crate::remote) fn address(&self) -> &Address {
         &self.address
     }
+
+    pub(in crate::remote) async fn close(&self, deadline: Instant) {
+        self.sender.channel.close(deadline).await
+    }
+
+    pub(in crate::remote) fn abort(&self, cause: &str) {
+        self.sender.channel.abort(cause)
+    }
+
+    pub(in crate::remote) fn closed(&self) -> Option<oneshot::Receiver<String>> {
+        self.sender.channel.closed()
+    }
+
+    pub(in crate::remote) fn last_read(&self) -> Instant {
+        self.sender.channel.last_read()
+    }
+
+    pub(in crate::remote) fn last_write(&self) -> Instant {
+        self.sender.channel.last_write()
+    }
 }
 
 impl Eq for Member {}
 
 impl PartialEq for Member {
     fn eq(&self, other: &Self) -> bool {
-        self.id.eq(&other.id)
+        self.address.eq(&other.address)
     }
 }
 
@@ -75,19 +180,90 @@ impl Sender {
         }
     }
 
-    async fn send<RQ: Request, RS: Response>(&self, request: RQ) -> Result<RS> {
+    async fn send<RQ: Request, RS: Response>(&self, request: &RQ) -> Result<RS> {
+        let message = self.channel.send((self.next_id(), request).into()).await?;
+        TryFrom::<RS>::try_from(message)
+    }
+
+    async fn subscribe<RQ: Request, RS: Response>(
+        &self,
+        request: &RQ,
+    ) -> Result<(u64, RS, mpsc::UnboundedReceiver<Message>)> {
+        let correlation_id = self.next_id();
+        let (message, events) = self.channel.subscribe((correlation_id, request).into()).await?;
+        TryFrom::<RS>::try_from(message).map(|response| (correlation_id, response, events))
+    }
+
+    fn next_id(&self) -> u64 {
         use std::convert::TryInto;
 
-        let id: u64 = self
-            .sequencer
+        self.sequencer
             .fetch_add(1, Ordering::SeqCst)
             .try_into()
-            .expect("unable to convert!");
-        let message = (id, request).into();
+            .expect("unable to convert!")
+    }
+}
 
-        match self.channel.send(message).await {
-            Ok(message) => TryFrom::<RS>::try_from(message),
-            Err(e) => Err(CommunicationFailure(e)),
+#[cfg(test)]
+mod tests {
+    use crate::{
+        messaging::{
+            authentication::{AuthenticationRequest, AuthenticationResponse},
+            ping::{PingRequest, PingResponse},
+        },
+        remote::test_server::{payload, Reply, TestServer},
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_authenticate() {
+        let server = TestServer::start().await.unwrap();
+
+        let (member, _) = connect(&server).await.unwrap();
+
+        assert_eq!(member.address(), &Address::from(&server.address()));
+        assert_eq!(server.received(AuthenticationRequest::r#type()), 1);
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_authenticate_with_rejected_credentials() {
+        let server = TestServer::start().await.unwrap();
+        server.respond(AuthenticationRequest::r#type(), |_| {
+            let none: Option<String> = None;
+            vec![Reply::response(
+                AuthenticationResponse::r#type(),
+                payload(&[&1u8, &Option::<Address>::None, &none, &none, &1u8, &Option::<u8>::None]),
+            )]
+        });
+
+        match connect(&server).await {
+            Err(AuthenticationFailure(status)) => assert_eq!(status, "CredentialsFailed"),
+            _ => panic!("unexpected result"),
         }
     }
+
+    #[tokio::test]
+    async fn should_send_request() {
+        let server = TestServer::start().await.unwrap();
+        let (member, _) = connect(&server).await.unwrap();
+
+        let response: PingResponse = member.send(&PingRequest::new()).await.unwrap();
+
+        assert_eq!(response, PingResponse {});
+        assert_eq!(server.received(PingRequest::r#type()), 1);
+    }
+
+    async fn connect(server: &TestServer) -> Result<(Member, Principal)> {
+        let config = ClientConfig::builder().build();
+        Member::connect(
+            &server.address(),
+            "127.0.0.1",
+            &ChannelOptions::new(&config).unwrap(),
+            &Credentials::from(&config),
+            None,
+            true,
+        )
+        .await
+    }
 }
@@ -0,0 +1,222 @@
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use tokio::sync::RwLock;
+
+use crate::messaging::ClusterMember;
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub enum MembershipEvent {
+    MemberAdded(ClusterMember),
+    MemberRemoved(ClusterMember),
+    MemberAttributeChanged {
+        member: ClusterMember,
+        key: String,
+        value: Option<String>,
+    },
+}
+
+type MembershipListener = Box<dyn Fn(&MembershipEvent) + Send + Sync>;
+
+pub(in crate::remote) struct Membership {
+    members: RwLock<Vec<ClusterMember>>,
+    listeners: RwLock<HashMap<u64, MembershipListener>>,
+    sequencer: AtomicU64,
+}
+
+impl Membership {
+    pub(in crate::remote) fn new() -> Self {
+        Membership {
+            members: RwLock::new(Vec::new()),
+            listeners: RwLock::new(HashMap::new()),
+            sequencer: AtomicU64::new(0),
+        }
+    }
+
+    pub(in crate::remote) async fn members(&self) -> Vec<ClusterMember> {
+        self.members.read().await.clone()
+    }
+
+    pub(in crate::remote) async fn add_listener(&self, listener: MembershipListener) -> u64 {
+        let id = self.sequencer.fetch_add(1, Ordering::SeqCst);
+        self.listeners.write().await.insert(id, listener);
+        id
+    }
+
+    pub(in crate::remote) async fn remove_listener(&self, id: u64) -> bool {
+        self.listeners.write().await.remove(&id).is_some()
+    }
+
+    pub(in crate::remote) async fn reset(&self, members: &[ClusterMember]) -> Vec<MembershipEvent> {
+        let events = {
+            let mut current = self.members.write().await;
+            let mut events: Vec<MembershipEvent> = current
+                .iter()
+                .filter(|member| !members.iter().any(|m| m.id() == member.id()))
+                .cloned()
+                .map(MembershipEvent::MemberRemoved)
+                .collect();
+            events.extend(
+                members
+                    .iter()
+                    .filter(|member| !current.iter().any(|m| m.id() == member.id()))
+                    .cloned()
+                    .map(MembershipEvent::MemberAdded),
+            );
+            *current = members.to_vec();
+            events
+        };
+
+        self.notify(&events).await;
+        events
+    }
+
+    pub(in crate::remote) async fn add(&self, member: &ClusterMember) -> Vec<MembershipEvent> {
+        let events = {
+            let mut current = self.members.write().await;
+            if current.iter().any(|m| m.id() == member.id()) {
+                vec![]
+            } else {
+                current.push(member.clone());
+                vec![MembershipEvent::MemberAdded(member.clone())]
+            }
+        };
+
+        self.notify(&events).await;
+        events
+    }
+
+    pub(in crate::remote) async fn remove(&self, member: &ClusterMember) -> Vec<MembershipEvent> {
+        let events = {
+            let mut current = self.members.write().await;
+            match current.iter().position(|m| m.id() == member.id()) {
+                Some(i) => vec![MembershipEvent::MemberRemoved(current.remove(i))],
+                None => vec![],
+            }
+        };
+
+        self.notify(&events).await;
+        events
+    }
+
+    pub(in crate::remote) async fn change_attribute(
+        &self,
+        id: &str,
+        key: &str,
+        value: Option<&str>,
+    ) -> Vec<MembershipEvent> {
+        let events = {
+            let mut current = self.members.write().await;
+            match current.iter_mut().find(|m| m.id() == id) {
+                Some(member) => {
+                    member.set_attribute(key, value);
+                    vec![MembershipEvent::MemberAttributeChanged {
+                        member: member.clone(),
+                        key: key.to_string(),
+                        value: value.map(str::to_string),
+                    }]
+                }
+                None => vec![],
+            }
+        };
+
+        self.notify(&events).await;
+        events
+    }
+
+    async fn notify(&self, events: &[MembershipEvent]) {
+        let listeners = self.listeners.read().await;
+        for event in events {
+            for listener in listeners.values() {
+                listener(event);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use std::sync::{Arc, Mutex};
+
+    use bytes::BytesMut;
+
+    use crate::codec::{Reader, Writer};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_emit_added_and_removed_on_reset() {
+        let membership = Membership::new();
+        let first = member("first");
+        let second = member("second");
+
+        assert_eq!(
+            membership.reset(std::slice::from_ref(&first)).await,
+            vec![MembershipEvent::MemberAdded(first.clone())]
+        );
+        assert_eq!(
+            membership.reset(std::slice::from_ref(&second)).await,
+            vec![
+                MembershipEvent::MemberRemoved(first),
+                MembershipEvent::MemberAdded(second.clone())
+            ]
+        );
+        assert_eq!(membership.members().await, vec![second]);
+    }
+
+    #[tokio::test]
+    async fn should_ignore_duplicated_add_and_unknown_remove() {
+        let membership = Membership::new();
+        let first = member("first");
+
+        assert_eq!(membership.add(&first).await.len(), 1);
+        assert!(membership.add(&first).await.is_empty());
+        assert!(membership.remove(&member("second")).await.is_empty());
+        assert_eq!(membership.members().await, vec![first]);
+    }
+
+    #[tokio::test]
+    async fn should_change_attribute() {
+        let membership = Membership::new();
+        let first = member("first");
+        membership.add(&first).await;
+
+        let events = membership.change_attribute("first", "key", Some("value")).await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(membership.members().await[0].attribute("key"), Some("value"));
+    }
+
+    #[tokio::test]
+    async fn should_notify_listeners_until_removed() {
+        let membership = Membership::new();
+        let events = Arc::new(Mutex::new(vec![]));
+
+        let captured = events.clone();
+        let id = membership
+            .add_listener(Box::new(move |event| captured.lock().unwrap().push(event.clone())))
+            .await;
+        membership.add(&member("first")).await;
+        assert!(membership.remove_listener(id).await);
+        membership.add(&member("second")).await;
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![MembershipEvent::MemberAdded(member("first"))]
+        );
+    }
+
+    fn member(id: &str) -> ClusterMember {
+        let writeable = &mut BytesMut::new();
+        "localhost".write_to(writeable);
+        5701u32.write_to(writeable);
+        id.write_to(writeable);
+        false.write_to(writeable);
+        0u32.write_to(writeable);
+
+        ClusterMember::read_from(&mut Cursor::new(writeable.split().freeze())).unwrap()
+    }
+}
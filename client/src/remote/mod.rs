@@ -1,33 +1,46 @@
-use std::convert::TryInto;
+use std::{convert::TryInto, io::Cursor};
 
 use bytes::{Buf, Bytes, BytesMut};
 
 use crate::{
+    codec::{self, DecodeError, Reader},
     messaging::{Request, Response},
-    HazelcastClientError, TryFrom,
+    HazelcastClientError::{self, CommunicationFailure},
+    TryFrom,
 };
 
-mod channel;
+pub(crate) mod channel;
 pub(crate) mod cluster;
+pub(crate) mod lifecycle;
+pub(crate) mod listener;
 mod member;
+pub(crate) mod membership;
+#[cfg(any(test, feature = "test-server"))]
+pub(crate) mod test_cluster;
+#[cfg(any(test, feature = "test-server"))]
+pub(crate) mod test_server;
+#[cfg(feature = "tls")]
+mod tls;
 
 const PROTOCOL_SEQUENCE: [u8; 3] = [0x43, 0x42, 0x32];
 
 const CLIENT_TYPE: &str = "Rust";
-const CLIENT_VERSION: &'static str = env!("CARGO_PKG_VERSION");
+const CLIENT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const PROTOCOL_VERSION: u8 = 1;
 
 const BEGIN_MESSAGE: u8 = 0x80;
 const END_MESSAGE: u8 = 0x40;
 const UNFRAGMENTED_MESSAGE: u8 = BEGIN_MESSAGE | END_MESSAGE;
+const LISTENER_EVENT_FLAG: u8 = 0x01;
 
 const LENGTH_FIELD_OFFSET: usize = 0;
 const LENGTH_FIELD_LENGTH: usize = 4;
 const LENGTH_FIELD_ADJUSTMENT: isize = -4;
 const HEADER_LENGTH: usize = 22;
+const DATA_OFFSET_FIELD_OFFSET: usize = 16;
 
 #[derive(Eq, PartialEq, Debug)]
-struct Message(u64, u16, Bytes);
+struct Message(u64, u16, u8, Bytes);
 
 impl Message {
     fn id(&self) -> u64 {
@@ -38,13 +51,17 @@ impl Message {
         self.1
     }
 
+    fn is_event(&self) -> bool {
+        self.2 & LISTENER_EVENT_FLAG != 0
+    }
+
     fn payload(&self) -> Bytes {
-        self.2.clone()
+        self.3.clone()
     }
 }
 
-impl<R: Request> From<(u64, R)> for Message {
-    fn from(request: (u64, R)) -> Self {
+impl<R: Request> From<(u64, &R)> for Message {
+    fn from(request: (u64, &R)) -> Self {
         use crate::codec::Writer;
 
         let mut frame = BytesMut::with_capacity(HEADER_LENGTH - LENGTH_FIELD_LENGTH + request.1.length());
@@ -59,24 +76,21 @@ impl<R: Request> From<(u64, R)> for Message {
         data_offset.write_to(&mut frame);
         request.1.write_to(&mut frame);
 
-        Message(request.0, R::r#type(), frame.to_bytes())
+        Message(request.0, R::r#type(), UNFRAGMENTED_MESSAGE, frame.to_bytes())
     }
 }
 
-impl From<Bytes> for Message {
-    fn from(mut frame: Bytes) -> Self {
-        use crate::codec::Readable;
-
-        let _version = frame.read_u8();
-        let _flags = frame.read_u8();
-        let message_type = frame.read_u16();
-        let correlation_id = frame.read_u64();
-        let _partition_id = frame.read_i32();
+impl std::convert::TryFrom<Bytes> for Message {
+    type Error = DecodeError;
 
-        let data_offset: usize = frame.read_u16().try_into().expect("unable to convert!");
-        frame.skip(data_offset - HEADER_LENGTH);
-
-        Message(correlation_id, message_type, frame.to_bytes())
+    fn try_from(frame: Bytes) -> codec::Result<Self> {
+        let header = Header::decode(&frame)?;
+        Ok(Message(
+            header.correlation_id,
+            header.message_type,
+            header.flags,
+            frame.slice(header.payload_offset()..),
+        ))
     }
 }
 
@@ -84,26 +98,46 @@ impl<R: Response> TryFrom<R> for Message {
     type Error = HazelcastClientError;
 
     fn try_from(self) -> Result<R, Self::Error> {
-        use crate::codec::Reader;
         use crate::messaging::error::Exception;
 
         let r#type = self.r#type();
-        let mut readable = self.payload();
+        let readable = &mut Cursor::new(self.payload());
 
         if r#type == R::r#type() {
-            Ok(R::read_from(&mut readable))
+            Ok(R::read_from(readable)?)
+        } else if r#type == Exception::r#type() {
+            Err(Exception::read_from(readable)?.into())
         } else {
-            assert_eq!(
-                r#type,
-                Exception::r#type(),
-                "unknown messaging type: {}, expected: {}",
-                r#type,
-                R::r#type()
-            );
-            Err(HazelcastClientError::ServerFailure(Box::new(Exception::read_from(
-                &mut readable,
-            ))))
+            Err(CommunicationFailure(
+                format!("unknown messaging type: {}, expected: {}", r#type, R::r#type()).into(),
+            ))
+        }
+    }
+}
+
+#[derive(Reader)]
+struct Header {
+    version: u8,
+    flags: u8,
+    message_type: u16,
+    correlation_id: u64,
+    partition_id: i32,
+    data_offset: u16,
+}
+
+impl Header {
+    fn decode(frame: &Bytes) -> codec::Result<Self> {
+        let readable = &mut Cursor::new(frame.clone());
+        let header = Header::read_from(readable)?;
+        let data_offset: usize = header.data_offset.into();
+        if data_offset < HEADER_LENGTH || data_offset - LENGTH_FIELD_LENGTH > frame.len() {
+            return Err(DecodeError::new(DATA_OFFSET_FIELD_OFFSET, "data offset"));
         }
+        Ok(header)
+    }
+
+    fn payload_offset(&self) -> usize {
+        usize::from(self.data_offset) - LENGTH_FIELD_LENGTH
     }
 }
 
@@ -118,7 +152,7 @@ mod tests {
         let id = 1;
         let request = SomeRequest { field: 2 };
 
-        let message: Message = (id, request).into();
+        let message: Message = (id, &request).into();
         assert_eq!(message.id(), id);
         assert_eq!(message.r#type(), SomeRequest::r#type());
         assert_eq!(
@@ -135,6 +169,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_convert_to_message_from_partition_aware_request() {
+        let request = SomePartitionAwareRequest {
+            field: 2,
+            partition_id: 3,
+        };
+
+        let message: Message = (1, &request).into();
+        assert_eq!(
+            message.payload().bytes(),
+            [
+                1,   // version
+                192, // flags
+                106, 0, // type
+                1, 0, 0, 0, 0, 0, 0, 0, // correlation id
+                3, 0, 0, 0, // partition id
+                22, 0, // data offset
+                2  // payload
+            ]
+        );
+    }
+
     #[test]
     fn should_convert_to_message_from_bytes() {
         let bytes = Bytes::copy_from_slice(&[
@@ -147,15 +203,96 @@ mod tests {
             2, // payload
         ]);
 
-        let message: Message = bytes.into();
+        let message: Message = bytes.try_into().unwrap();
         assert_eq!(message.id(), 1);
         assert_eq!(message.r#type(), 0x69);
+        assert!(!message.is_event());
+        assert_eq!(message.payload().bytes(), [2]);
+    }
+
+    #[test]
+    fn should_convert_to_event_message_from_bytes() {
+        let bytes = Bytes::copy_from_slice(&[
+            1,   // version
+            193, // flags
+            0xC8, 0, // type
+            1, 0, 0, 0, 0, 0, 0, 0, // correlation id
+            255, 255, 255, 255, // partition id
+            22, 0, // data offset
+            2, // payload
+        ]);
+
+        let message: Message = bytes.try_into().unwrap();
+        assert_eq!(message.id(), 1);
+        assert_eq!(message.r#type(), 0xC8);
+        assert!(message.is_event());
         assert_eq!(message.payload().bytes(), [2]);
     }
 
+    #[test]
+    fn should_fail_to_convert_truncated_bytes() {
+        let bytes = Bytes::copy_from_slice(&[1, 192, 0x69, 0, 1, 0, 0, 0]);
+
+        assert_eq!(
+            std::convert::TryInto::<Message>::try_into(bytes),
+            Err(DecodeError::new(4, "u64"))
+        );
+    }
+
+    #[test]
+    fn should_fail_to_convert_bytes_with_invalid_data_offset() {
+        let bytes = Bytes::copy_from_slice(&[
+            1,   // version
+            192, // flags
+            0x69, 0, // type
+            1, 0, 0, 0, 0, 0, 0, 0, // correlation id
+            255, 255, 255, 255, // partition id
+            10, 0, // data offset
+        ]);
+
+        assert_eq!(
+            std::convert::TryInto::<Message>::try_into(bytes),
+            Err(DecodeError::new(DATA_OFFSET_FIELD_OFFSET, "data offset"))
+        );
+    }
+
+    #[test]
+    fn should_fail_to_convert_malformed_response() {
+        let message = Message(1, SomeResponse::r#type(), UNFRAGMENTED_MESSAGE, Bytes::new());
+
+        assert!(matches!(
+            TryFrom::<SomeResponse>::try_from(message),
+            Err(CommunicationFailure(_))
+        ));
+    }
+
+    #[test]
+    fn should_fail_to_convert_message_of_unknown_type() {
+        let message = Message(1, 0x01, UNFRAGMENTED_MESSAGE, Bytes::copy_from_slice(&[2]));
+
+        assert!(matches!(
+            TryFrom::<SomeResponse>::try_from(message),
+            Err(CommunicationFailure(_))
+        ));
+    }
+
     #[derive(Request, Eq, PartialEq, Debug)]
     #[r#type = 0x69]
     struct SomeRequest {
         field: u8,
     }
+
+    #[derive(Request, Eq, PartialEq, Debug)]
+    #[r#type = 0x6A]
+    struct SomePartitionAwareRequest {
+        field: u8,
+        #[partition_id]
+        partition_id: i32,
+    }
+
+    #[derive(Response, Eq, PartialEq, Debug)]
+    #[r#type = 0x6B]
+    struct SomeResponse {
+        field: u32,
+    }
 }
@@ -0,0 +1,702 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    io, mem,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use bytes::Bytes;
+use tokio::{sync::mpsc, time};
+
+use crate::{
+    messaging::{
+        map::{
+            MapClearRequest, MapClearResponse, MapContainsKeyRequest, MapContainsKeyResponse, MapGetRequest,
+            MapGetResponse, MapPutRequest, MapPutResponse, MapRemoveRequest, MapRemoveResponse, MapSizeRequest,
+            MapSizeResponse,
+        },
+        membership::AddMembershipListenerRequest,
+        partition::GetPartitionsRequest,
+        pn_counter::{
+            PnCounterAddRequest, PnCounterAddResponse, PnCounterGetReplicaCountRequest,
+            PnCounterGetReplicaCountResponse, PnCounterGetRequest, PnCounterGetResponse,
+        },
+        queue::{
+            ItemEvent, QueueAddListenerRequest, QueueAddListenerResponse, QueueDrainToMaxSizeRequest,
+            QueueDrainToMaxSizeResponse, QueueOfferRequest, QueueOfferResponse, QueuePeekRequest, QueuePeekResponse,
+            QueuePollRequest, QueuePollResponse, QueueRemainingCapacityRequest, QueueRemainingCapacityResponse,
+            QueueRemoveListenerRequest, QueueRemoveListenerResponse, QueueSizeRequest, QueueSizeResponse,
+            QueueTakeRequest, QueueTakeResponse, ITEM_ADDED, ITEM_REMOVED,
+        },
+        ringbuffer::{
+            RingbufferAddRequest, RingbufferAddResponse, RingbufferHeadSequenceRequest, RingbufferHeadSequenceResponse,
+            RingbufferReadManyRequest, RingbufferReadManyResponse, RingbufferTailSequenceRequest,
+            RingbufferTailSequenceResponse, FAIL,
+        },
+        topic::{
+            TopicAddMessageListenerRequest, TopicAddMessageListenerResponse, TopicEvent, TopicPublishRequest,
+            TopicPublishResponse, TopicRemoveMessageListenerRequest, TopicRemoveMessageListenerResponse,
+        },
+        Address, ReplicaTimestampEntry, Request, Response,
+    },
+    remote::test_server::{decode, membership, partitions, payload, Reply, TestServer},
+    serialization::Data,
+};
+
+const STALE_SEQUENCE: i32 = 54;
+const QUEUE_CAPACITY: u32 = i32::MAX as u32;
+const RINGBUFFER_CAPACITY: usize = 16;
+
+pub struct TestCluster {
+    members: Vec<TestServer>,
+}
+
+impl TestCluster {
+    pub async fn start(size: usize) -> io::Result<Self> {
+        let mut members = Vec::with_capacity(size);
+        for _ in 0..size {
+            members.push(TestServer::start().await?);
+        }
+
+        let roster: Vec<(Address, String)> = members
+            .iter()
+            .map(|member| (Address::from(&member.address()), member.member_id().to_string()))
+            .collect();
+        let store = Arc::new(Store::new(size));
+        for member in &members {
+            serve(member, &roster, &store);
+        }
+
+        Ok(TestCluster { members })
+    }
+
+    pub fn addresses(&self) -> Vec<SocketAddr> {
+        self.members.iter().map(TestServer::address).collect()
+    }
+
+    pub fn members(&self) -> &[TestServer] {
+        &self.members
+    }
+}
+
+fn serve(member: &TestServer, roster: &[(Address, String)], store: &Arc<Store>) {
+    let owners: Vec<Address> = roster.iter().map(|(address, _)| address.clone()).collect();
+    member.respond(GetPartitionsRequest::r#type(), move |_| vec![partitions(&owners)]);
+
+    let members = roster.to_vec();
+    member.respond(AddMembershipListenerRequest::r#type(), move |_| membership(&members));
+
+    let (replica_id, state) = (member.member_id().to_string(), store.clone());
+    member.respond(PnCounterGetRequest::r#type(), move |request| {
+        decode(request, |request: CounterGet| {
+            let (value, timestamps) = state.count(&request.name, &replica_id, 0);
+            vec![Reply::response(
+                PnCounterGetResponse::r#type(),
+                payload(&[&value, &timestamps.as_slice()]),
+            )]
+        })
+    });
+
+    let (replica_id, state) = (member.member_id().to_string(), store.clone());
+    member.respond(PnCounterAddRequest::r#type(), move |request| {
+        decode(request, |request: CounterAdd| {
+            let (value, timestamps) = state.count(&request.name, &replica_id, request.delta);
+            let value = if request.get_before_update {
+                value - request.delta
+            } else {
+                value
+            };
+            vec![Reply::response(
+                PnCounterAddResponse::r#type(),
+                payload(&[&value, &timestamps.as_slice(), &state.replica_count]),
+            )]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(PnCounterGetReplicaCountRequest::r#type(), move |request| {
+        decode(request, |_: CounterGetReplicaCount| {
+            vec![Reply::response(
+                PnCounterGetReplicaCountResponse::r#type(),
+                payload(&[&state.replica_count]),
+            )]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(MapPutRequest::r#type(), move |request| {
+        decode(request, |MapPut { name, key, value, .. }| {
+            let previous = state.map(&name, |entries| entries.insert(key, value));
+            vec![Reply::response(MapPutResponse::r#type(), payload(&[&previous]))]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(MapGetRequest::r#type(), move |request| {
+        decode(request, |request: MapKey| {
+            let value = state.map(&request.name, |entries| entries.get(&request.key).cloned());
+            vec![Reply::response(MapGetResponse::r#type(), payload(&[&value]))]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(MapRemoveRequest::r#type(), move |request| {
+        decode(request, |request: MapKey| {
+            let previous = state.map(&request.name, |entries| entries.remove(&request.key));
+            vec![Reply::response(MapRemoveResponse::r#type(), payload(&[&previous]))]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(MapContainsKeyRequest::r#type(), move |request| {
+        decode(request, |request: MapKey| {
+            let contains = state.map(&request.name, |entries| entries.contains_key(&request.key));
+            vec![Reply::response(MapContainsKeyResponse::r#type(), payload(&[&contains]))]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(MapSizeRequest::r#type(), move |request| {
+        decode(request, |request: Name| {
+            let size = state.map(&request.name, |entries| entries.len() as u32);
+            vec![Reply::response(MapSizeResponse::r#type(), payload(&[&size]))]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(MapClearRequest::r#type(), move |request| {
+        decode(request, |request: Name| {
+            state.map(&request.name, |entries| entries.clear());
+            vec![Reply::response(MapClearResponse::r#type(), Bytes::new())]
+        })
+    });
+
+    let (member_id, state) = (member.member_id().to_string(), store.clone());
+    member.respond(QueueOfferRequest::r#type(), move |request| {
+        decode(request, |QueueOffer { name, value, .. }| {
+            state.queue(&name, |queue| queue.offer(value, &member_id));
+            vec![Reply::response(QueueOfferResponse::r#type(), payload(&[&true]))]
+        })
+    });
+
+    let (member_id, state) = (member.member_id().to_string(), store.clone());
+    member.respond(QueuePollRequest::r#type(), move |request| {
+        decode(request, |QueuePoll { name, timeout }| {
+            let timeout = Some(Duration::from_millis(timeout.max(0) as u64));
+            wait(&state, name, QueuePollResponse::r#type(), &member_id, timeout)
+        })
+    });
+
+    let (member_id, state) = (member.member_id().to_string(), store.clone());
+    member.respond(QueueTakeRequest::r#type(), move |request| {
+        decode(request, |Name { name }| {
+            wait(&state, name, QueueTakeResponse::r#type(), &member_id, None)
+        })
+    });
+
+    let state = store.clone();
+    member.respond(QueuePeekRequest::r#type(), move |request| {
+        decode(request, |request: Name| {
+            let value = state.queue(&request.name, |queue| queue.items.front().cloned());
+            vec![Reply::response(QueuePeekResponse::r#type(), payload(&[&value]))]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(QueueSizeRequest::r#type(), move |request| {
+        decode(request, |request: Name| {
+            let size = state.queue(&request.name, |queue| queue.items.len() as u32);
+            vec![Reply::response(QueueSizeResponse::r#type(), payload(&[&size]))]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(QueueRemainingCapacityRequest::r#type(), move |request| {
+        decode(request, |request: Name| {
+            let capacity = state.queue(&request.name, |queue| QUEUE_CAPACITY - queue.items.len() as u32);
+            vec![Reply::response(
+                QueueRemainingCapacityResponse::r#type(),
+                payload(&[&capacity]),
+            )]
+        })
+    });
+
+    let (member_id, state) = (member.member_id().to_string(), store.clone());
+    member.respond(QueueDrainToMaxSizeRequest::r#type(), move |request| {
+        decode(request, |request: QueueDrain| {
+            let values: Vec<Data> = state.queue(&request.name, |queue| {
+                let count = match request.max_size {
+                    max_size if max_size < 0 => queue.items.len(),
+                    max_size => queue.items.len().min(max_size as usize),
+                };
+                (0..count).filter_map(|_| queue.poll(&member_id)).collect()
+            });
+            vec![Reply::response(
+                QueueDrainToMaxSizeResponse::r#type(),
+                payload(&[&values.as_slice()]),
+            )]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(QueueAddListenerRequest::r#type(), move |request| {
+        decode(request, |request: QueueAddListener| {
+            let (events, replies) = mpsc::unbounded_channel();
+            let id = format!(
+                "00000000-0000-0000-0001-{:012}",
+                state.sequencer.fetch_add(1, Ordering::SeqCst)
+            );
+            let _ = events.send(Reply::response(QueueAddListenerResponse::r#type(), payload(&[&id])));
+            state.queue(&request.name, |queue| {
+                queue.listeners.insert(id.clone(), (request.include_value, events))
+            });
+            vec![Reply::deferred(replies)]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(QueueRemoveListenerRequest::r#type(), move |request| {
+        decode(request, |request: RemoveListener| {
+            let removed = state.queue(&request.name, |queue| queue.listeners.remove(&request.id).is_some());
+            vec![Reply::response(
+                QueueRemoveListenerResponse::r#type(),
+                payload(&[&removed]),
+            )]
+        })
+    });
+
+    let (member_id, state) = (member.member_id().to_string(), store.clone());
+    member.respond(TopicPublishRequest::r#type(), move |request| {
+        decode(request, |request: TopicPublish| {
+            let publish_time = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_millis() as i64)
+                .unwrap_or(0);
+            state.topic(&request.name, |listeners| {
+                listeners.retain(|_, events| {
+                    events
+                        .send(Reply::event(
+                            TopicEvent::r#type(),
+                            payload(&[&request.message, &publish_time, &member_id]),
+                        ))
+                        .is_ok()
+                })
+            });
+            vec![Reply::response(TopicPublishResponse::r#type(), Bytes::new())]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(TopicAddMessageListenerRequest::r#type(), move |request| {
+        decode(request, |request: TopicAddListener| {
+            let (id, (events, replies)) = (state.listener_id(), mpsc::unbounded_channel());
+            let _ = events.send(Reply::response(
+                TopicAddMessageListenerResponse::r#type(),
+                payload(&[&id]),
+            ));
+            state.topic(&request.name, |listeners| listeners.insert(id, events));
+            vec![Reply::deferred(replies)]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(TopicRemoveMessageListenerRequest::r#type(), move |request| {
+        decode(request, |request: RemoveListener| {
+            let removed = state.topic(&request.name, |listeners| listeners.remove(&request.id).is_some());
+            vec![Reply::response(
+                TopicRemoveMessageListenerResponse::r#type(),
+                payload(&[&removed]),
+            )]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(RingbufferTailSequenceRequest::r#type(), move |request| {
+        decode(request, |request: Name| {
+            let sequence = state.ringbuffer(&request.name, |ringbuffer| ringbuffer.tail());
+            vec![Reply::response(
+                RingbufferTailSequenceResponse::r#type(),
+                payload(&[&sequence]),
+            )]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(RingbufferHeadSequenceRequest::r#type(), move |request| {
+        decode(request, |request: Name| {
+            let sequence = state.ringbuffer(&request.name, |ringbuffer| ringbuffer.head);
+            vec![Reply::response(
+                RingbufferHeadSequenceResponse::r#type(),
+                payload(&[&sequence]),
+            )]
+        })
+    });
+
+    let state = store.clone();
+    member.respond(RingbufferAddRequest::r#type(), move |request| {
+        decode(
+            request,
+            |RingbufferAdd {
+                 name,
+                 overflow_policy,
+                 value,
+             }| {
+                let sequence = state.ringbuffer(&name, |ringbuffer| match ringbuffer.items.len() {
+                    RINGBUFFER_CAPACITY if overflow_policy == FAIL => -1,
+                    _ => ringbuffer.add(value),
+                });
+                vec![Reply::response(RingbufferAddResponse::r#type(), payload(&[&sequence]))]
+            },
+        )
+    });
+
+    let state = store.clone();
+    member.respond(RingbufferReadManyRequest::r#type(), move |request| {
+        decode(request, |request: RingbufferRead| {
+            state.ringbuffer(&request.name.clone(), |ringbuffer| match ringbuffer.read(&request) {
+                Some(reply) => vec![reply],
+                None => {
+                    let (replies, receiver) = mpsc::unbounded_channel();
+                    ringbuffer.readers.push((request, replies));
+                    vec![Reply::deferred(receiver)]
+                }
+            })
+        })
+    });
+}
+
+fn wait(state: &Arc<Store>, name: String, r#type: u16, member_id: &str, timeout: Option<Duration>) -> Vec<Reply> {
+    let immediate = timeout == Some(Duration::from_millis(0));
+    let (replies, receiver) = mpsc::unbounded_channel();
+    let id = state.sequencer.fetch_add(1, Ordering::SeqCst);
+    let polled = state.queue(&name, |queue| {
+        let polled = queue.poll(member_id);
+        if polled.is_none() && !immediate {
+            queue.waiters.push_back((id, r#type, replies.clone()));
+        }
+        polled
+    });
+    if polled.is_some() || immediate {
+        return vec![Reply::response(r#type, payload(&[&polled]))];
+    }
+
+    if let Some(timeout) = timeout {
+        let state = state.clone();
+        tokio::spawn(async move {
+            time::delay_for(timeout).await;
+            if state.queue(&name, |queue| queue.expire(id)) {
+                let _ = replies.send(Reply::response(r#type, payload(&[&Option::<Data>::None])));
+            }
+        });
+    }
+    vec![Reply::deferred(receiver)]
+}
+
+struct Store {
+    replica_count: u32,
+    counters: Mutex<HashMap<String, HashMap<String, Replica>>>,
+    maps: Mutex<HashMap<String, HashMap<Data, Data>>>,
+    queues: Mutex<HashMap<String, Queue>>,
+    topics: Mutex<HashMap<String, HashMap<String, mpsc::UnboundedSender<Reply>>>>,
+    ringbuffers: Mutex<HashMap<String, Ringbuffer>>,
+    sequencer: AtomicU64,
+}
+
+#[derive(Default)]
+struct Replica {
+    value: i64,
+    timestamp: i64,
+}
+
+impl Store {
+    fn new(replica_count: usize) -> Self {
+        Store {
+            replica_count: replica_count as u32,
+            counters: Mutex::new(HashMap::new()),
+            maps: Mutex::new(HashMap::new()),
+            queues: Mutex::new(HashMap::new()),
+            topics: Mutex::new(HashMap::new()),
+            ringbuffers: Mutex::new(HashMap::new()),
+            sequencer: AtomicU64::new(0),
+        }
+    }
+
+    fn count(&self, name: &str, replica_id: &str, delta: i64) -> (i64, Vec<Timestamp>) {
+        let mut counters = self.counters.lock().expect("poisoned lock!");
+        let replicas = counters.entry(name.to_string()).or_default();
+        if delta != 0 {
+            let replica = replicas.entry(replica_id.to_string()).or_default();
+            replica.value += delta;
+            replica.timestamp += 1;
+        }
+
+        let value = replicas.values().map(|replica| replica.value).sum();
+        let timestamps = replicas
+            .iter()
+            .map(|(replica_id, replica)| Timestamp {
+                replica_id: replica_id.clone(),
+                timestamp: replica.timestamp,
+            })
+            .collect();
+        (value, timestamps)
+    }
+
+    fn map<T, F>(&self, name: &str, operation: F) -> T
+    where
+        F: FnOnce(&mut HashMap<Data, Data>) -> T,
+    {
+        let mut maps = self.maps.lock().expect("poisoned lock!");
+        operation(maps.entry(name.to_string()).or_default())
+    }
+
+    fn queue<T, F>(&self, name: &str, operation: F) -> T
+    where
+        F: FnOnce(&mut Queue) -> T,
+    {
+        let mut queues = self.queues.lock().expect("poisoned lock!");
+        operation(queues.entry(name.to_string()).or_default())
+    }
+
+    fn topic<T, F>(&self, name: &str, operation: F) -> T
+    where
+        F: FnOnce(&mut HashMap<String, mpsc::UnboundedSender<Reply>>) -> T,
+    {
+        let mut topics = self.topics.lock().expect("poisoned lock!");
+        operation(topics.entry(name.to_string()).or_default())
+    }
+
+    fn ringbuffer<T, F>(&self, name: &str, operation: F) -> T
+    where
+        F: FnOnce(&mut Ringbuffer) -> T,
+    {
+        let mut ringbuffers = self.ringbuffers.lock().expect("poisoned lock!");
+        operation(ringbuffers.entry(name.to_string()).or_default())
+    }
+
+    fn listener_id(&self) -> String {
+        format!(
+            "00000000-0000-0000-0001-{:012}",
+            self.sequencer.fetch_add(1, Ordering::SeqCst)
+        )
+    }
+}
+
+#[derive(Default)]
+struct Queue {
+    items: VecDeque<Data>,
+    waiters: VecDeque<(u64, u16, mpsc::UnboundedSender<Reply>)>,
+    listeners: HashMap<String, (bool, mpsc::UnboundedSender<Reply>)>,
+}
+
+impl Queue {
+    fn offer(&mut self, value: Data, member_id: &str) {
+        self.publish(&value, ITEM_ADDED, member_id);
+        while let Some((_, r#type, waiter)) = self.waiters.pop_front() {
+            if waiter
+                .send(Reply::response(r#type, payload(&[&Some(value.clone())])))
+                .is_ok()
+            {
+                return self.publish(&value, ITEM_REMOVED, member_id);
+            }
+        }
+        self.items.push_back(value);
+    }
+
+    fn poll(&mut self, member_id: &str) -> Option<Data> {
+        let value = self.items.pop_front()?;
+        self.publish(&value, ITEM_REMOVED, member_id);
+        Some(value)
+    }
+
+    fn expire(&mut self, id: u64) -> bool {
+        let waiters = self.waiters.len();
+        self.waiters.retain(|(waiter, _, _)| *waiter != id);
+        self.waiters.len() != waiters
+    }
+
+    fn publish(&mut self, value: &Data, event_type: i32, member_id: &str) {
+        self.listeners.retain(|_, (include_value, events)| {
+            let item = if *include_value { Some(value.clone()) } else { None };
+            events
+                .send(Reply::event(
+                    ItemEvent::r#type(),
+                    payload(&[&item, &member_id, &event_type]),
+                ))
+                .is_ok()
+        });
+    }
+}
+
+#[derive(Default)]
+struct Ringbuffer {
+    head: i64,
+    items: VecDeque<Data>,
+    readers: Vec<(RingbufferRead, mpsc::UnboundedSender<Reply>)>,
+}
+
+impl Ringbuffer {
+    fn tail(&self) -> i64 {
+        self.head + self.items.len() as i64 - 1
+    }
+
+    fn add(&mut self, value: Data) -> i64 {
+        self.items.push_back(value);
+        if self.items.len() > RINGBUFFER_CAPACITY {
+            self.items.pop_front();
+            self.head += 1;
+        }
+
+        for (request, replies) in mem::take(&mut self.readers) {
+            match self.read(&request) {
+                Some(reply) => {
+                    let _ = replies.send(reply);
+                }
+                None => self.readers.push((request, replies)),
+            }
+        }
+        self.tail()
+    }
+
+    fn read(&self, request: &RingbufferRead) -> Option<Reply> {
+        if request.start_sequence < self.head {
+            return Some(Reply::exception(
+                STALE_SEQUENCE,
+                "com.hazelcast.ringbuffer.StaleSequenceException",
+            ));
+        }
+
+        let items: Vec<Data> = self
+            .items
+            .iter()
+            .skip((request.start_sequence - self.head) as usize)
+            .take(request.max_count as usize)
+            .cloned()
+            .collect();
+        if (items.len() as i32) < request.min_count {
+            return None;
+        }
+        let sequences: Vec<i64> = (request.start_sequence..).take(items.len()).collect();
+        let next_sequence = request.start_sequence + items.len() as i64;
+        Some(Reply::response(
+            RingbufferReadManyResponse::r#type(),
+            payload(&[
+                &(items.len() as i32),
+                &items.as_slice(),
+                &Some(sequences.as_slice()),
+                &next_sequence,
+            ]),
+        ))
+    }
+}
+
+#[derive(Writer)]
+struct Timestamp {
+    replica_id: String,
+    timestamp: i64,
+}
+
+#[derive(Reader)]
+struct CounterGet {
+    name: String,
+    _replica_timestamps: Vec<ReplicaTimestampEntry>,
+    _address: Address,
+}
+
+#[derive(Reader)]
+struct CounterAdd {
+    name: String,
+    delta: i64,
+    get_before_update: bool,
+    _replica_timestamps: Vec<ReplicaTimestampEntry>,
+    _address: Address,
+}
+
+#[derive(Reader)]
+struct CounterGetReplicaCount {
+    _name: String,
+}
+
+#[derive(Reader)]
+struct MapPut {
+    name: String,
+    key: Data,
+    value: Data,
+    _thread_id: i64,
+    _ttl: i64,
+}
+
+#[derive(Reader)]
+struct MapKey {
+    name: String,
+    key: Data,
+    _thread_id: i64,
+}
+
+#[derive(Reader)]
+struct Name {
+    name: String,
+}
+
+#[derive(Reader)]
+struct QueueOffer {
+    name: String,
+    value: Data,
+    _timeout: i64,
+}
+
+#[derive(Reader)]
+struct QueuePoll {
+    name: String,
+    timeout: i64,
+}
+
+#[derive(Reader)]
+struct QueueDrain {
+    name: String,
+    max_size: i32,
+}
+
+#[derive(Reader)]
+struct QueueAddListener {
+    name: String,
+    include_value: bool,
+    _local_only: bool,
+}
+
+#[derive(Reader)]
+struct RemoveListener {
+    name: String,
+    id: String,
+}
+
+#[derive(Reader)]
+struct TopicPublish {
+    name: String,
+    message: Data,
+}
+
+#[derive(Reader)]
+struct TopicAddListener {
+    name: String,
+    _local_only: bool,
+}
+
+#[derive(Reader)]
+struct RingbufferAdd {
+    name: String,
+    overflow_policy: i32,
+    value: Data,
+}
+
+#[derive(Reader)]
+struct RingbufferRead {
+    name: String,
+    start_sequence: i64,
+    min_count: i32,
+    max_count: i32,
+    _filter: Option<Data>,
+}
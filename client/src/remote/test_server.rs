@@ -0,0 +1,455 @@
+use std::{
+    collections::HashMap,
+    convert::TryInto,
+    io::{self, Cursor},
+    net::SocketAddr,
+    sync::{Arc, Mutex, RwLock},
+};
+
+use bytes::{Bytes, BytesMut};
+use futures::SinkExt;
+use log::warn;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    prelude::*,
+    stream::StreamExt,
+    sync::{broadcast, mpsc, oneshot},
+};
+use tokio_util::codec::{length_delimited, LengthDelimitedCodec};
+
+use crate::{
+    codec::{Reader, Writer},
+    messaging::{
+        authentication::{AuthenticationRequest, AuthenticationResponse},
+        error::Exception,
+        membership::{AddMembershipListenerRequest, AddMembershipListenerResponse, MemberListEvent},
+        partition::{GetPartitionsRequest, GetPartitionsResponse},
+        ping::{PingRequest, PingResponse},
+        Address, Request, Response,
+    },
+    remote::{
+        channel::Assembler, Message, HEADER_LENGTH, LENGTH_FIELD_ADJUSTMENT, LENGTH_FIELD_LENGTH, LENGTH_FIELD_OFFSET,
+        LISTENER_EVENT_FLAG, PROTOCOL_SEQUENCE, PROTOCOL_VERSION, UNFRAGMENTED_MESSAGE,
+    },
+};
+
+const LISTENER_ID: &str = "00000000-0000-0000-0000-000000000000";
+const PARTITION_COUNT: u32 = 271;
+const AUTHENTICATED: u8 = 0;
+const HAZELCAST_SERIALIZATION: i32 = 23;
+
+type Handler = Box<dyn Fn(Bytes) -> Vec<Reply> + Send + Sync>;
+
+pub struct TestServer {
+    address: SocketAddr,
+    member_id: String,
+    state: Arc<State>,
+    disconnector: broadcast::Sender<()>,
+    _handle: oneshot::Sender<()>,
+}
+
+impl TestServer {
+    pub async fn start() -> io::Result<Self> {
+        TestServer::bind(Acceptor {
+            #[cfg(feature = "tls")]
+            tls: None,
+        })
+        .await
+    }
+
+    #[cfg(feature = "tls")]
+    pub async fn start_tls(config: tokio_rustls::rustls::ServerConfig) -> io::Result<Self> {
+        TestServer::bind(Acceptor {
+            tls: Some(tokio_rustls::TlsAcceptor::from(Arc::new(config))),
+        })
+        .await
+    }
+
+    async fn bind(acceptor: Acceptor) -> io::Result<Self> {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let state = Arc::new(State::new());
+        let (disconnector, _) = broadcast::channel(1);
+
+        let (handle, mut receiver) = oneshot::channel::<()>();
+        let server_state = state.clone();
+        let server_disconnector = disconnector.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = &mut receiver => return,
+                    accepted = listener.accept() => match accepted {
+                        Ok((socket, _)) => {
+                            let acceptor = acceptor.clone();
+                            let state = server_state.clone();
+                            let disconnect = server_disconnector.subscribe();
+                            tokio::spawn(async move {
+                                if let Err(e) = acceptor.serve(socket, state, disconnect).await {
+                                    warn!("Test server connection failed - {}", e);
+                                }
+                            });
+                        }
+                        Err(e) => return warn!("Test server failed to accept connection - {}", e),
+                    }
+                }
+            }
+        });
+
+        let server = TestServer {
+            address,
+            member_id: format!("00000000-0000-0000-0000-{:012}", address.port()),
+            state,
+            disconnector,
+            _handle: handle,
+        };
+        server.respond_by_default();
+        Ok(server)
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    pub fn respond<F>(&self, r#type: u16, handler: F)
+    where
+        F: Fn(Bytes) -> Vec<Reply> + Send + Sync + 'static,
+    {
+        self.state
+            .handlers
+            .write()
+            .expect("poisoned lock!")
+            .insert(r#type, Box::new(handler));
+    }
+
+    pub fn respond_with<RQ, RS, F>(&self, request_type: u16, response_type: u16, handler: F)
+    where
+        RQ: Reader,
+        RS: Writer,
+        F: Fn(RQ) -> RS + Send + Sync + 'static,
+    {
+        self.respond(request_type, move |request| {
+            decode(request, |request| {
+                vec![Reply::response(response_type, payload(&[&handler(request)]))]
+            })
+        });
+    }
+
+    pub fn received(&self, r#type: u16) -> usize {
+        *self
+            .state
+            .received
+            .lock()
+            .expect("poisoned lock!")
+            .get(&r#type)
+            .unwrap_or(&0)
+    }
+
+    pub fn disconnect(&self) {
+        let _ = self.disconnector.send(());
+    }
+
+    pub fn member_id(&self) -> &str {
+        &self.member_id
+    }
+
+    fn respond_by_default(&self) {
+        let address = Address::from(&self.address);
+        let member_id = self.member_id.clone();
+
+        let (member, id) = (address.clone(), member_id.clone());
+        self.respond(AuthenticationRequest::r#type(), move |_| {
+            vec![Reply::response(
+                AuthenticationResponse::r#type(),
+                payload(&[
+                    &AUTHENTICATED,
+                    &Some(member.clone()),
+                    &Some(id.as_str()),
+                    &Some(id.as_str()),
+                    &PROTOCOL_VERSION,
+                    &Option::<u8>::None,
+                ]),
+            )]
+        });
+
+        self.respond(PingRequest::r#type(), |_| {
+            vec![Reply::response(PingResponse::r#type(), Bytes::new())]
+        });
+
+        let owners = vec![address.clone()];
+        self.respond(GetPartitionsRequest::r#type(), move |_| vec![partitions(&owners)]);
+
+        let members = vec![(address, member_id)];
+        self.respond(AddMembershipListenerRequest::r#type(), move |_| membership(&members));
+    }
+}
+
+pub struct Reply {
+    r#type: u16,
+    flags: u8,
+    payload: Bytes,
+    deferred: Option<mpsc::UnboundedReceiver<Reply>>,
+}
+
+impl Reply {
+    pub fn response(r#type: u16, payload: Bytes) -> Self {
+        Reply {
+            r#type,
+            flags: UNFRAGMENTED_MESSAGE,
+            payload,
+            deferred: None,
+        }
+    }
+
+    pub fn event(r#type: u16, payload: Bytes) -> Self {
+        Reply {
+            r#type,
+            flags: UNFRAGMENTED_MESSAGE | LISTENER_EVENT_FLAG,
+            payload,
+            deferred: None,
+        }
+    }
+
+    pub fn deferred(replies: mpsc::UnboundedReceiver<Reply>) -> Self {
+        Reply {
+            r#type: 0,
+            flags: 0,
+            payload: Bytes::new(),
+            deferred: Some(replies),
+        }
+    }
+
+    pub fn exception(code: i32, class_name: &str) -> Self {
+        Reply::response(
+            Exception::r#type(),
+            payload(&[
+                &code,
+                &class_name,
+                &Option::<u8>::None,
+                &0u32,
+                &0u32,
+                &Option::<u8>::None,
+            ]),
+        )
+    }
+
+    fn frame(&self, correlation_id: u64) -> Bytes {
+        let mut frame = BytesMut::with_capacity(HEADER_LENGTH - LENGTH_FIELD_LENGTH + self.payload.len());
+        let data_offset: u16 = HEADER_LENGTH.try_into().expect("unable to convert");
+
+        PROTOCOL_VERSION.write_to(&mut frame);
+        self.flags.write_to(&mut frame);
+        self.r#type.write_to(&mut frame);
+        correlation_id.write_to(&mut frame);
+        (-1i32).write_to(&mut frame);
+        data_offset.write_to(&mut frame);
+        frame.extend_from_slice(&self.payload);
+        frame.freeze()
+    }
+}
+
+pub fn payload(fields: &[&dyn Writer]) -> Bytes {
+    let mut payload = BytesMut::new();
+    for field in fields {
+        field.write_to(&mut payload);
+    }
+    payload.freeze()
+}
+
+pub(crate) fn decode<R, H>(request: Bytes, handler: H) -> Vec<Reply>
+where
+    R: Reader,
+    H: FnOnce(R) -> Vec<Reply>,
+{
+    match R::read_from(&mut Cursor::new(request)) {
+        Ok(request) => handler(request),
+        Err(e) => {
+            warn!("Test server failed to decode request - {}", e);
+            vec![Reply::exception(
+                HAZELCAST_SERIALIZATION,
+                "com.hazelcast.nio.serialization.HazelcastSerializationException",
+            )]
+        }
+    }
+}
+
+pub(crate) fn partitions(owners: &[Address]) -> Reply {
+    let ids: Vec<Vec<u32>> = (0..owners.len())
+        .map(|owner| {
+            (0..PARTITION_COUNT)
+                .filter(|id| *id as usize % owners.len() == owner)
+                .collect()
+        })
+        .collect();
+    let table: Vec<PartitionOwner> = owners
+        .iter()
+        .zip(&ids)
+        .map(|(address, ids)| PartitionOwner {
+            address,
+            partition_ids: ids,
+        })
+        .collect();
+    Reply::response(GetPartitionsResponse::r#type(), payload(&[&table.as_slice(), &0u32]))
+}
+
+pub(crate) fn membership(members: &[(Address, String)]) -> Vec<Reply> {
+    let members: Vec<Member> = members
+        .iter()
+        .map(|(address, id)| Member {
+            address,
+            id,
+            lite: false,
+            attribute_count: 0,
+        })
+        .collect();
+    vec![
+        Reply::response(AddMembershipListenerResponse::r#type(), payload(&[&LISTENER_ID])),
+        Reply::event(MemberListEvent::r#type(), payload(&[&members.as_slice()])),
+    ]
+}
+
+#[derive(Writer)]
+struct PartitionOwner<'a> {
+    address: &'a Address,
+    partition_ids: &'a [u32],
+}
+
+#[derive(Writer)]
+struct Member<'a> {
+    address: &'a Address,
+    id: &'a str,
+    lite: bool,
+    attribute_count: u32,
+}
+
+struct State {
+    handlers: RwLock<HashMap<u16, Handler>>,
+    received: Mutex<HashMap<u16, usize>>,
+}
+
+impl State {
+    fn new() -> Self {
+        State {
+            handlers: RwLock::new(HashMap::new()),
+            received: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn handle(&self, message: &Message) -> Vec<Reply> {
+        *self
+            .received
+            .lock()
+            .expect("poisoned lock!")
+            .entry(message.r#type())
+            .or_insert(0) += 1;
+
+        match self.handlers.read().expect("poisoned lock!").get(&message.r#type()) {
+            Some(handler) => handler(message.payload()),
+            None => {
+                warn!("No response scripted for message type {}.", message.r#type());
+                vec![]
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Acceptor {
+    #[cfg(feature = "tls")]
+    tls: Option<tokio_rustls::TlsAcceptor>,
+}
+
+impl Acceptor {
+    async fn serve(&self, socket: TcpStream, state: Arc<State>, disconnect: broadcast::Receiver<()>) -> io::Result<()> {
+        #[cfg(feature = "tls")]
+        {
+            if let Some(tls) = &self.tls {
+                return serve(tls.accept(socket).await?, state, disconnect).await;
+            }
+        }
+        serve(socket, state, disconnect).await
+    }
+}
+
+async fn serve<S>(mut socket: S, state: Arc<State>, mut disconnect: broadcast::Receiver<()>) -> io::Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut protocol = [0u8; PROTOCOL_SEQUENCE.len()];
+    socket.read_exact(&mut protocol).await?;
+
+    let (reader, writer) = tokio::io::split(socket);
+    let mut reader = codec().new_read(reader);
+    let mut writer = codec().new_write(writer);
+    let mut assembler = Assembler::new();
+    let (deferrer, mut deferred) = mpsc::unbounded_channel::<(u64, Reply)>();
+    loop {
+        let frame = tokio::select! {
+            _ = disconnect.recv() => return Ok(()),
+            Some((id, reply)) = deferred.recv() => {
+                writer.send(reply.frame(id)).await?;
+                continue;
+            }
+            frame = reader.next() => match frame {
+                Some(frame) => frame?,
+                None => return Ok(()),
+            },
+        };
+
+        let message: Message = match assembler.assemble(frame.freeze()) {
+            Ok(Some(frame)) => frame
+                .try_into()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            Ok(None) => continue,
+            Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+        };
+        for mut reply in state.handle(&message) {
+            match reply.deferred.take() {
+                Some(mut replies) => {
+                    let (id, deferrer) = (message.id(), deferrer.clone());
+                    tokio::spawn(async move {
+                        while let Some(reply) = replies.recv().await {
+                            if deferrer.send((id, reply)).is_err() {
+                                return;
+                            }
+                        }
+                    });
+                }
+                None => writer.send(reply.frame(message.id())).await?,
+            }
+        }
+    }
+}
+
+fn codec() -> length_delimited::Builder {
+    let mut builder = LengthDelimitedCodec::builder();
+    builder
+        .length_field_offset(LENGTH_FIELD_OFFSET)
+        .length_field_length(LENGTH_FIELD_LENGTH)
+        .length_adjustment(LENGTH_FIELD_ADJUSTMENT)
+        .little_endian();
+    builder
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        messaging::map::{MapSizeRequest, MapSizeResponse},
+        HazelcastClient,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_respond_with_typed_handler() {
+        let server = TestServer::start().await.unwrap();
+        server.respond_with(MapSizeRequest::r#type(), MapSizeResponse::r#type(), |name: String| {
+            name.len() as u32
+        });
+        let client = HazelcastClient::new(HazelcastClient::builder().address(server.address()).build())
+            .await
+            .unwrap();
+
+        assert_eq!(client.map::<String, String>("some-map").size().await.unwrap(), 8);
+        assert_eq!(server.received(MapSizeRequest::r#type()), 1);
+    }
+}
@@ -0,0 +1,345 @@
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    net::IpAddr,
+    path::Path,
+    sync::Arc,
+    time::SystemTime,
+};
+
+use tokio::net::TcpStream;
+use tokio_rustls::{
+    client::TlsStream,
+    rustls::{
+        internal::pemfile, Certificate, ClientConfig, PrivateKey, RootCertStore, ServerCertVerified,
+        ServerCertVerifier, TLSError,
+    },
+    TlsConnector,
+};
+use webpki::{DNSName, DNSNameRef, EndEntityCert, SignatureAlgorithm, TLSServerTrustAnchors, TrustAnchor};
+
+use crate::{
+    config::TlsConfig,
+    HazelcastClientError::{self, InvalidConfiguration},
+    Result,
+};
+
+const UNVERIFIED_SERVER_NAME: &str = "hazelcast";
+
+static SIGNATURE_ALGORITHMS: &[&SignatureAlgorithm] = &[
+    &webpki::ECDSA_P256_SHA256,
+    &webpki::ECDSA_P256_SHA384,
+    &webpki::ECDSA_P384_SHA256,
+    &webpki::ECDSA_P384_SHA384,
+    &webpki::ED25519,
+    &webpki::RSA_PSS_2048_8192_SHA256_LEGACY_KEY,
+    &webpki::RSA_PSS_2048_8192_SHA384_LEGACY_KEY,
+    &webpki::RSA_PSS_2048_8192_SHA512_LEGACY_KEY,
+    &webpki::RSA_PKCS1_2048_8192_SHA256,
+    &webpki::RSA_PKCS1_2048_8192_SHA384,
+    &webpki::RSA_PKCS1_2048_8192_SHA512,
+    &webpki::RSA_PKCS1_3072_8192_SHA384,
+];
+
+pub(in crate::remote) struct Connector {
+    config: Arc<ClientConfig>,
+    server_name: Option<DNSName>,
+    verify_hostname: bool,
+}
+
+impl Connector {
+    pub(in crate::remote) fn new(config: &TlsConfig) -> Result<Self> {
+        let mut client_config = ClientConfig::new();
+        if config.ca_bundles().is_empty() {
+            client_config
+                .root_store
+                .add_server_trust_anchors(&webpki_roots::TLS_SERVER_ROOTS);
+        }
+        for ca_bundle in config.ca_bundles() {
+            match client_config.root_store.add_pem_file(&mut open(ca_bundle)?) {
+                Ok((valid, _)) if valid > 0 => {}
+                _ => return Err(invalid(ca_bundle, "no valid CA certificates")),
+            }
+        }
+
+        if let Some((certificate_chain, private_key)) = config.client_certificate() {
+            let certificates = certificates(certificate_chain)?;
+            let private_key = self::private_key(private_key)?;
+            client_config
+                .set_single_client_cert(certificates, private_key)
+                .map_err(|e| InvalidConfiguration(format!("invalid client certificate - {}", e)))?;
+        }
+
+        if !config.verify_hostname() {
+            client_config
+                .dangerous()
+                .set_certificate_verifier(Arc::new(ChainVerifier));
+        }
+        let server_name = match config.server_name() {
+            Some(server_name) => Some(
+                DNSNameRef::try_from_ascii_str(server_name)
+                    .map_err(|_| InvalidConfiguration(format!("invalid ssl server name: {}", server_name)))?
+                    .to_owned(),
+            ),
+            None => None,
+        };
+
+        Ok(Connector {
+            config: Arc::new(client_config),
+            server_name,
+            verify_hostname: config.verify_hostname(),
+        })
+    }
+
+    pub(in crate::remote) async fn connect(&self, host: &str, stream: TcpStream) -> io::Result<TlsStream<TcpStream>> {
+        let server_name = match (&self.server_name, host.parse::<IpAddr>()) {
+            (Some(server_name), _) => server_name.as_ref(),
+            (None, _) if !self.verify_hostname => unverified_server_name(),
+            (None, Ok(_)) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("ssl server name required to verify member address: {}", host),
+                ))
+            }
+            (None, Err(_)) => DNSNameRef::try_from_ascii_str(host)
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, format!("invalid host name: {}", host)))?,
+        };
+        TlsConnector::from(self.config.clone())
+            .connect(server_name, stream)
+            .await
+    }
+}
+
+fn unverified_server_name() -> DNSNameRef<'static> {
+    DNSNameRef::try_from_ascii_str(UNVERIFIED_SERVER_NAME).expect("invalid server name!")
+}
+
+struct ChainVerifier;
+
+impl ServerCertVerifier for ChainVerifier {
+    fn verify_server_cert(
+        &self,
+        roots: &RootCertStore,
+        presented_certs: &[Certificate],
+        _dns_name: DNSNameRef<'_>,
+        _ocsp_response: &[u8],
+    ) -> std::result::Result<ServerCertVerified, TLSError> {
+        let (certificate, chain) = presented_certs.split_first().ok_or(TLSError::NoCertificatesPresented)?;
+        let certificate = EndEntityCert::from(&certificate.0).map_err(TLSError::WebPKIError)?;
+        let chain: Vec<&[u8]> = chain.iter().map(|certificate| certificate.0.as_ref()).collect();
+        let anchors: Vec<TrustAnchor> = roots.roots.iter().map(|root| root.to_trust_anchor()).collect();
+        let now = webpki::Time::try_from(SystemTime::now()).map_err(|_| TLSError::FailedToGetCurrentTime)?;
+
+        certificate
+            .verify_is_valid_tls_server_cert(SIGNATURE_ALGORITHMS, &TLSServerTrustAnchors(&anchors), &chain, now)
+            .map_err(TLSError::WebPKIError)
+            .map(|_| ServerCertVerified::assertion())
+    }
+}
+
+fn certificates(path: &Path) -> Result<Vec<Certificate>> {
+    match pemfile::certs(&mut open(path)?) {
+        Ok(certificates) if !certificates.is_empty() => Ok(certificates),
+        _ => Err(invalid(path, "no valid certificates")),
+    }
+}
+
+fn private_key(path: &Path) -> Result<PrivateKey> {
+    let mut keys = pemfile::pkcs8_private_keys(&mut open(path)?).unwrap_or_default();
+    if keys.is_empty() {
+        keys = pemfile::rsa_private_keys(&mut open(path)?).unwrap_or_default();
+    }
+    keys.pop().ok_or_else(|| invalid(path, "no valid private key"))
+}
+
+fn open(path: &Path) -> Result<BufReader<File>> {
+    File::open(path)
+        .map(BufReader::new)
+        .map_err(|e| InvalidConfiguration(format!("unable to read {} - {}", path.display(), e)))
+}
+
+fn invalid(path: &Path, reason: &str) -> HazelcastClientError {
+    InvalidConfiguration(format!("{} in {}", reason, path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        path::PathBuf,
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    use rcgen::{BasicConstraints, CertificateParams, IsCa};
+    use tokio_rustls::rustls::{AllowAnyAuthenticatedClient, NoClientAuth, ServerConfig};
+
+    use crate::{
+        config::ClientConfigBuilder,
+        messaging::{authentication::AuthenticationRequest, Request},
+        remote::test_server::TestServer,
+        ClientConfig, HazelcastClient,
+    };
+
+    use super::*;
+
+    static SEQUENCER: AtomicUsize = AtomicUsize::new(0);
+
+    #[tokio::test]
+    async fn should_connect_to_trusted_member() {
+        let authority = Authority::new();
+        let server = TestServer::start_tls(authority.server_config(false)).await.unwrap();
+
+        let builder = config(&server)
+            .tls_ca_bundle(authority.bundle())
+            .tls_server_name("localhost");
+
+        assert!(HazelcastClient::new(builder.build()).await.is_ok());
+        assert_eq!(server.received(AuthenticationRequest::r#type()), 1);
+    }
+
+    #[tokio::test]
+    async fn should_require_server_name_for_member_address() {
+        let authority = Authority::new();
+        let server = TestServer::start_tls(authority.server_config(false)).await.unwrap();
+
+        let builder = config(&server).tls_ca_bundle(authority.bundle());
+        assert!(HazelcastClient::new(builder.build()).await.is_err());
+        assert_eq!(server.received(AuthenticationRequest::r#type()), 0);
+    }
+
+    #[tokio::test]
+    async fn should_reject_untrusted_member() {
+        let server = TestServer::start_tls(Authority::new().server_config(false))
+            .await
+            .unwrap();
+
+        let builder = config(&server)
+            .tls_ca_bundle(Authority::new().bundle())
+            .tls_verify_hostname(false);
+
+        assert!(HazelcastClient::new(builder.build()).await.is_err());
+        assert_eq!(server.received(AuthenticationRequest::r#type()), 0);
+    }
+
+    #[tokio::test]
+    async fn should_verify_hostname_unless_disabled() {
+        let authority = Authority::new();
+        let server = TestServer::start_tls(authority.server_config(false)).await.unwrap();
+
+        let builder = config(&server)
+            .tls_ca_bundle(authority.bundle())
+            .tls_server_name("other.example");
+        assert!(HazelcastClient::new(builder.build()).await.is_err());
+
+        let builder = config(&server)
+            .tls_ca_bundle(authority.bundle())
+            .tls_server_name("other.example")
+            .tls_verify_hostname(false);
+        assert!(HazelcastClient::new(builder.build()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_authenticate_with_client_certificate() {
+        let authority = Authority::new();
+        let server = TestServer::start_tls(authority.server_config(true)).await.unwrap();
+
+        let builder = config(&server)
+            .tls_ca_bundle(authority.bundle())
+            .tls_server_name("localhost");
+        assert!(HazelcastClient::new(builder.build()).await.is_err());
+
+        let (certificate, private_key) = authority.issue("client");
+        let builder = config(&server)
+            .tls_ca_bundle(authority.bundle())
+            .tls_server_name("localhost")
+            .tls_client_certificate(certificate, private_key);
+        assert!(HazelcastClient::new(builder.build()).await.is_ok());
+    }
+
+    #[test]
+    fn should_fail_on_invalid_configuration() {
+        let config = |builder: ClientConfigBuilder| builder.build().tls().unwrap().clone();
+
+        assert!(Connector::new(&config(ClientConfig::builder().tls(true))).is_ok());
+        assert!(Connector::new(&config(ClientConfig::builder().tls_ca_bundle("missing.pem"))).is_err());
+        assert!(Connector::new(&config(ClientConfig::builder().tls_server_name("not a name"))).is_err());
+        assert!(Connector::new(&config(ClientConfig::builder().tls_server_name("localhost"))).is_ok());
+    }
+
+    fn config(server: &TestServer) -> ClientConfigBuilder {
+        ClientConfig::builder()
+            .address(server.address())
+            .connection_timeout(Duration::from_secs(1))
+    }
+
+    struct Authority {
+        directory: PathBuf,
+        certificate: rcgen::Certificate,
+    }
+
+    impl Authority {
+        fn new() -> Self {
+            let directory = env::temp_dir().join(format!(
+                "hazelcast-tls-{}-{}",
+                std::process::id(),
+                SEQUENCER.fetch_add(1, Ordering::SeqCst)
+            ));
+            fs::create_dir_all(&directory).unwrap();
+
+            let mut params = CertificateParams::new(vec![]);
+            params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+            Authority {
+                directory,
+                certificate: rcgen::Certificate::from_params(params).unwrap(),
+            }
+        }
+
+        fn bundle(&self) -> PathBuf {
+            let path = self.directory.join("ca.pem");
+            fs::write(&path, self.certificate.serialize_pem().unwrap()).unwrap();
+            path
+        }
+
+        fn issue(&self, name: &str) -> (PathBuf, PathBuf) {
+            let certificate = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+            let (certificate_path, private_key_path) = (
+                self.directory.join(format!("{}.pem", name)),
+                self.directory.join(format!("{}.key", name)),
+            );
+            fs::write(
+                &certificate_path,
+                certificate.serialize_pem_with_signer(&self.certificate).unwrap(),
+            )
+            .unwrap();
+            fs::write(&private_key_path, certificate.serialize_private_key_pem()).unwrap();
+            (certificate_path, private_key_path)
+        }
+
+        fn server_config(&self, client_authentication: bool) -> ServerConfig {
+            let mut config = if client_authentication {
+                let mut roots = RootCertStore::empty();
+                roots
+                    .add(&Certificate(self.certificate.serialize_der().unwrap()))
+                    .unwrap();
+                ServerConfig::new(AllowAnyAuthenticatedClient::new(roots))
+            } else {
+                ServerConfig::new(NoClientAuth::new())
+            };
+            let (certificate, private_key) = self.issue("server");
+            config
+                .set_single_cert(
+                    certificates(&certificate).unwrap(),
+                    self::private_key(&private_key).unwrap(),
+                )
+                .unwrap();
+            config
+        }
+    }
+
+    impl Drop for Authority {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.directory);
+        }
+    }
+}
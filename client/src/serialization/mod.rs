@@ -0,0 +1,715 @@
+use std::{convert::TryInto, mem};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+use crate::{
+    codec::{self, Readable, Reader, Writeable, Writer},
+    HazelcastClientError::SerializationFailure,
+    Result,
+};
+
+const PARTITION_HASH_OFFSET: usize = 0;
+const TYPE_OFFSET: usize = 4;
+const DATA_OFFSET: usize = 8;
+
+const MURMUR3_SEED: u32 = 0x0100_0193;
+
+const NULL_TYPE_ID: i32 = 0;
+const BYTE_TYPE_ID: i32 = -3;
+const BOOLEAN_TYPE_ID: i32 = -4;
+const SHORT_TYPE_ID: i32 = -6;
+const INTEGER_TYPE_ID: i32 = -7;
+const LONG_TYPE_ID: i32 = -8;
+const FLOAT_TYPE_ID: i32 = -9;
+const DOUBLE_TYPE_ID: i32 = -10;
+const STRING_TYPE_ID: i32 = -11;
+const BYTE_ARRAY_TYPE_ID: i32 = -12;
+const INTEGER_ARRAY_TYPE_ID: i32 = -16;
+const LONG_ARRAY_TYPE_ID: i32 = -17;
+const STRING_ARRAY_TYPE_ID: i32 = -20;
+
+pub trait Serializer: Sized {
+    fn type_id() -> i32;
+
+    fn write_to(&self, output: &mut ObjectDataOutput);
+
+    fn read_from(input: &mut ObjectDataInput) -> Result<Self>;
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct SerializationService {}
+
+impl SerializationService {
+    pub(crate) fn to_data<T: Serializer>(&self, value: &T) -> Data {
+        let mut output = ObjectDataOutput::new();
+        output.write_i32(0);
+        output.write_i32(T::type_id());
+        value.write_to(&mut output);
+
+        Data(output.buffer.to_bytes())
+    }
+
+    pub(crate) fn to_object<T: Serializer>(&self, data: &Data) -> Result<T> {
+        if data.0.len() < DATA_OFFSET || data.type_id() == NULL_TYPE_ID {
+            return Err(SerializationFailure("unexpected null value".to_string()));
+        }
+        if data.type_id() != T::type_id() {
+            return Err(SerializationFailure(format!(
+                "unexpected type id: {}, expected: {}",
+                data.type_id(),
+                T::type_id()
+            )));
+        }
+
+        T::read_from(&mut ObjectDataInput::new(data.payload()))
+    }
+}
+
+pub struct ObjectDataOutput {
+    buffer: BytesMut,
+}
+
+impl ObjectDataOutput {
+    fn new() -> Self {
+        ObjectDataOutput {
+            buffer: BytesMut::new(),
+        }
+    }
+
+    pub fn write_bool(&mut self, value: bool) {
+        self.buffer.put_u8(if value { 1 } else { 0 });
+    }
+
+    pub fn write_i8(&mut self, value: i8) {
+        self.buffer.put_i8(value);
+    }
+
+    pub fn write_i16(&mut self, value: i16) {
+        self.buffer.put_i16(value);
+    }
+
+    pub fn write_i32(&mut self, value: i32) {
+        self.buffer.put_i32(value);
+    }
+
+    pub fn write_i64(&mut self, value: i64) {
+        self.buffer.put_i64(value);
+    }
+
+    pub fn write_f32(&mut self, value: f32) {
+        self.buffer.put_f32(value);
+    }
+
+    pub fn write_f64(&mut self, value: f64) {
+        self.buffer.put_f64(value);
+    }
+
+    pub fn write_string(&mut self, value: &str) {
+        let len: i32 = value.encode_utf16().count().try_into().expect("unable to convert!");
+        self.write_i32(len);
+        for c in value.encode_utf16() {
+            match c {
+                0x0000..=0x007F => self.buffer.put_u8(c as u8),
+                0x0080..=0x07FF => {
+                    self.buffer.put_u8((0xC0 | ((c >> 6) & 0x1F)) as u8);
+                    self.buffer.put_u8((0x80 | (c & 0x3F)) as u8);
+                }
+                _ => {
+                    self.buffer.put_u8((0xE0 | ((c >> 12) & 0x0F)) as u8);
+                    self.buffer.put_u8((0x80 | ((c >> 6) & 0x3F)) as u8);
+                    self.buffer.put_u8((0x80 | (c & 0x3F)) as u8);
+                }
+            }
+        }
+    }
+
+    pub fn write_bytes(&mut self, value: &[u8]) {
+        self.write_i32(value.len().try_into().expect("unable to convert!"));
+        self.buffer.put_slice(value);
+    }
+
+    pub fn write_i32_array(&mut self, value: &[i32]) {
+        self.write_i32(value.len().try_into().expect("unable to convert!"));
+        value.iter().for_each(|v| self.write_i32(*v));
+    }
+
+    pub fn write_i64_array(&mut self, value: &[i64]) {
+        self.write_i32(value.len().try_into().expect("unable to convert!"));
+        value.iter().for_each(|v| self.write_i64(*v));
+    }
+
+    pub fn write_string_array(&mut self, value: &[String]) {
+        self.write_i32(value.len().try_into().expect("unable to convert!"));
+        value.iter().for_each(|v| self.write_string(v));
+    }
+
+    pub(crate) fn write_data(&mut self, value: &Data) {
+        self.write_bytes(&value.0);
+    }
+}
+
+pub struct ObjectDataInput {
+    buffer: Bytes,
+}
+
+impl ObjectDataInput {
+    fn new(buffer: Bytes) -> Self {
+        ObjectDataInput { buffer }
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool> {
+        Ok(self.read_i8()? != 0)
+    }
+
+    pub fn read_i8(&mut self) -> Result<i8> {
+        self.ensure(mem::size_of::<i8>())?;
+        Ok(self.buffer.get_i8())
+    }
+
+    pub fn read_i16(&mut self) -> Result<i16> {
+        self.ensure(mem::size_of::<i16>())?;
+        Ok(self.buffer.get_i16())
+    }
+
+    pub fn read_i32(&mut self) -> Result<i32> {
+        self.ensure(mem::size_of::<i32>())?;
+        Ok(self.buffer.get_i32())
+    }
+
+    pub fn read_i64(&mut self) -> Result<i64> {
+        self.ensure(mem::size_of::<i64>())?;
+        Ok(self.buffer.get_i64())
+    }
+
+    pub fn read_f32(&mut self) -> Result<f32> {
+        self.ensure(mem::size_of::<f32>())?;
+        Ok(self.buffer.get_f32())
+    }
+
+    pub fn read_f64(&mut self) -> Result<f64> {
+        self.ensure(mem::size_of::<f64>())?;
+        Ok(self.buffer.get_f64())
+    }
+
+    pub fn read_string(&mut self) -> Result<String> {
+        let len = self.read_len()?;
+        let mut chars = Vec::with_capacity(len);
+        for _ in 0..len {
+            self.ensure(1)?;
+            let first = u16::from(self.buffer.get_u8());
+            let c = match first >> 4 {
+                0..=7 => first,
+                12 | 13 => {
+                    self.ensure(1)?;
+                    ((first & 0x1F) << 6) | (u16::from(self.buffer.get_u8()) & 0x3F)
+                }
+                14 => {
+                    self.ensure(2)?;
+                    let second = u16::from(self.buffer.get_u8());
+                    let third = u16::from(self.buffer.get_u8());
+                    ((first & 0x0F) << 12) | ((second & 0x3F) << 6) | (third & 0x3F)
+                }
+                _ => return Err(SerializationFailure(format!("malformed utf8 byte: {}", first))),
+            };
+            chars.push(c);
+        }
+
+        String::from_utf16(&chars).map_err(|e| SerializationFailure(e.to_string()))
+    }
+
+    pub fn read_bytes(&mut self) -> Result<Vec<u8>> {
+        let len = self.read_len()?;
+        self.ensure(len)?;
+        Ok(self.buffer.split_to(len).to_vec())
+    }
+
+    pub fn read_i32_array(&mut self) -> Result<Vec<i32>> {
+        let len = self.read_len()?;
+        (0..len).map(|_| self.read_i32()).collect()
+    }
+
+    pub fn read_i64_array(&mut self) -> Result<Vec<i64>> {
+        let len = self.read_len()?;
+        (0..len).map(|_| self.read_i64()).collect()
+    }
+
+    pub fn read_string_array(&mut self) -> Result<Vec<String>> {
+        let len = self.read_len()?;
+        (0..len).map(|_| self.read_string()).collect()
+    }
+
+    pub(crate) fn read_data(&mut self) -> Result<Data> {
+        match self.read_bytes()? {
+            bytes if bytes.len() < DATA_OFFSET => Err(SerializationFailure("unexpected null value".to_string())),
+            bytes => Ok(Data(Bytes::from(bytes))),
+        }
+    }
+
+    fn read_len(&mut self) -> Result<usize> {
+        match self.read_i32()? {
+            len if len >= 0 => Ok(len as usize),
+            len => Err(SerializationFailure(format!("unexpected null length: {}", len))),
+        }
+    }
+
+    fn ensure(&self, len: usize) -> Result<()> {
+        if self.buffer.remaining() < len {
+            Err(SerializationFailure(format!(
+                "unexpected end of data, required: {}, remaining: {}",
+                len,
+                self.buffer.remaining()
+            )))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Serializer for bool {
+    fn type_id() -> i32 {
+        BOOLEAN_TYPE_ID
+    }
+
+    fn write_to(&self, output: &mut ObjectDataOutput) {
+        output.write_bool(*self);
+    }
+
+    fn read_from(input: &mut ObjectDataInput) -> Result<Self> {
+        input.read_bool()
+    }
+}
+
+impl Serializer for i8 {
+    fn type_id() -> i32 {
+        BYTE_TYPE_ID
+    }
+
+    fn write_to(&self, output: &mut ObjectDataOutput) {
+        output.write_i8(*self);
+    }
+
+    fn read_from(input: &mut ObjectDataInput) -> Result<Self> {
+        input.read_i8()
+    }
+}
+
+impl Serializer for i16 {
+    fn type_id() -> i32 {
+        SHORT_TYPE_ID
+    }
+
+    fn write_to(&self, output: &mut ObjectDataOutput) {
+        output.write_i16(*self);
+    }
+
+    fn read_from(input: &mut ObjectDataInput) -> Result<Self> {
+        input.read_i16()
+    }
+}
+
+impl Serializer for i32 {
+    fn type_id() -> i32 {
+        INTEGER_TYPE_ID
+    }
+
+    fn write_to(&self, output: &mut ObjectDataOutput) {
+        output.write_i32(*self);
+    }
+
+    fn read_from(input: &mut ObjectDataInput) -> Result<Self> {
+        input.read_i32()
+    }
+}
+
+impl Serializer for i64 {
+    fn type_id() -> i32 {
+        LONG_TYPE_ID
+    }
+
+    fn write_to(&self, output: &mut ObjectDataOutput) {
+        output.write_i64(*self);
+    }
+
+    fn read_from(input: &mut ObjectDataInput) -> Result<Self> {
+        input.read_i64()
+    }
+}
+
+impl Serializer for f32 {
+    fn type_id() -> i32 {
+        FLOAT_TYPE_ID
+    }
+
+    fn write_to(&self, output: &mut ObjectDataOutput) {
+        output.write_f32(*self);
+    }
+
+    fn read_from(input: &mut ObjectDataInput) -> Result<Self> {
+        input.read_f32()
+    }
+}
+
+impl Serializer for f64 {
+    fn type_id() -> i32 {
+        DOUBLE_TYPE_ID
+    }
+
+    fn write_to(&self, output: &mut ObjectDataOutput) {
+        output.write_f64(*self);
+    }
+
+    fn read_from(input: &mut ObjectDataInput) -> Result<Self> {
+        input.read_f64()
+    }
+}
+
+impl Serializer for String {
+    fn type_id() -> i32 {
+        STRING_TYPE_ID
+    }
+
+    fn write_to(&self, output: &mut ObjectDataOutput) {
+        output.write_string(self);
+    }
+
+    fn read_from(input: &mut ObjectDataInput) -> Result<Self> {
+        input.read_string()
+    }
+}
+
+impl Serializer for Vec<u8> {
+    fn type_id() -> i32 {
+        BYTE_ARRAY_TYPE_ID
+    }
+
+    fn write_to(&self, output: &mut ObjectDataOutput) {
+        output.write_bytes(self);
+    }
+
+    fn read_from(input: &mut ObjectDataInput) -> Result<Self> {
+        input.read_bytes()
+    }
+}
+
+impl Serializer for Vec<i32> {
+    fn type_id() -> i32 {
+        INTEGER_ARRAY_TYPE_ID
+    }
+
+    fn write_to(&self, output: &mut ObjectDataOutput) {
+        output.write_i32_array(self);
+    }
+
+    fn read_from(input: &mut ObjectDataInput) -> Result<Self> {
+        input.read_i32_array()
+    }
+}
+
+impl Serializer for Vec<i64> {
+    fn type_id() -> i32 {
+        LONG_ARRAY_TYPE_ID
+    }
+
+    fn write_to(&self, output: &mut ObjectDataOutput) {
+        output.write_i64_array(self);
+    }
+
+    fn read_from(input: &mut ObjectDataInput) -> Result<Self> {
+        input.read_i64_array()
+    }
+}
+
+impl Serializer for Vec<String> {
+    fn type_id() -> i32 {
+        STRING_ARRAY_TYPE_ID
+    }
+
+    fn write_to(&self, output: &mut ObjectDataOutput) {
+        output.write_string_array(self);
+    }
+
+    fn read_from(input: &mut ObjectDataInput) -> Result<Self> {
+        input.read_string_array()
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Debug, Clone)]
+pub(crate) struct Data(Bytes);
+
+impl Data {
+    pub(crate) fn partition_hash(&self) -> i32 {
+        match (&self.0[PARTITION_HASH_OFFSET..TYPE_OFFSET]).get_i32() {
+            0 => murmur3_32(&self.0[DATA_OFFSET..], MURMUR3_SEED) as i32,
+            hash => hash,
+        }
+    }
+
+    fn type_id(&self) -> i32 {
+        (&self.0[TYPE_OFFSET..DATA_OFFSET]).get_i32()
+    }
+
+    fn payload(&self) -> Bytes {
+        self.0.slice(DATA_OFFSET..)
+    }
+}
+
+impl Writer for Data {
+    fn length(&self) -> usize {
+        mem::size_of::<u32>() + self.0.len()
+    }
+
+    fn write_to(&self, writeable: &mut dyn Writeable) {
+        let len: u32 = self.0.len().try_into().expect("unable to convert!");
+        len.write_to(writeable);
+        self.0.write_to(writeable);
+    }
+}
+
+impl Reader for Data {
+    fn read_from(readable: &mut dyn Readable) -> codec::Result<Self> {
+        let len = readable.read_u32()?.try_into().expect("unable to convert!");
+        Ok(Data(readable.read_slice(len)?))
+    }
+}
+
+fn murmur3_32(data: &[u8], seed: u32) -> u32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+
+    let mut hash = seed;
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes(chunk.try_into().expect("unable to convert!"));
+        k = k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        hash ^= k;
+        hash = hash.rotate_left(13).wrapping_mul(5).wrapping_add(0xe654_6b64);
+    }
+
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let mut k = tail
+            .iter()
+            .enumerate()
+            .fold(0u32, |k, (i, byte)| k ^ (u32::from(*byte) << (8 * i)));
+        k = k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        hash ^= k;
+    }
+
+    hash ^= data.len() as u32;
+    hash ^= hash >> 16;
+    hash = hash.wrapping_mul(0x85eb_ca6b);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(0xc2b2_ae35);
+    hash ^= hash >> 16;
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn should_serialize_and_deserialize_primitives() {
+        let service = SerializationService::default();
+
+        assert!(service.to_object::<bool>(&service.to_data(&true)).unwrap());
+        assert_eq!(service.to_object::<i8>(&service.to_data(&-8i8)).unwrap(), -8);
+        assert_eq!(service.to_object::<i16>(&service.to_data(&-16i16)).unwrap(), -16);
+        assert_eq!(service.to_object::<i32>(&service.to_data(&-32i32)).unwrap(), -32);
+        assert_eq!(service.to_object::<i64>(&service.to_data(&-64i64)).unwrap(), -64);
+        assert_eq!(service.to_object::<f32>(&service.to_data(&3.2f32)).unwrap(), 3.2);
+        assert_eq!(service.to_object::<f64>(&service.to_data(&6.4f64)).unwrap(), 6.4);
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize_arrays() {
+        let service = SerializationService::default();
+
+        let bytes = vec![1u8, 2, 3];
+        assert_eq!(service.to_object::<Vec<u8>>(&service.to_data(&bytes)).unwrap(), bytes);
+        let integers = vec![-1i32, 0, 1];
+        assert_eq!(
+            service.to_object::<Vec<i32>>(&service.to_data(&integers)).unwrap(),
+            integers
+        );
+        let longs = vec![-1i64, 0, 1];
+        assert_eq!(service.to_object::<Vec<i64>>(&service.to_data(&longs)).unwrap(), longs);
+        let strings = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(
+            service.to_object::<Vec<String>>(&service.to_data(&strings)).unwrap(),
+            strings
+        );
+    }
+
+    #[test]
+    fn should_write_java_compatible_integer() {
+        let data = SerializationService::default().to_data(&1i32);
+
+        assert_eq!(
+            data.0[..],
+            [
+                0, 0, 0, 0, // partition hash
+                255, 255, 255, 249, // type id
+                0, 0, 0, 1 // value
+            ]
+        );
+    }
+
+    #[test]
+    fn should_write_java_compatible_byte_array() {
+        let data = SerializationService::default().to_data(&vec![1u8, 2]);
+
+        assert_eq!(
+            data.0[..],
+            [
+                0, 0, 0, 0, // partition hash
+                255, 255, 255, 244, // type id
+                0, 0, 0, 2, // length
+                1, 2 // bytes
+            ]
+        );
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize_custom_type() {
+        let service = SerializationService::default();
+        let value = SomeType {
+            id: 1,
+            name: "name".to_string(),
+        };
+
+        let data = service.to_data(&value);
+        assert_eq!(data.type_id(), 1000);
+        assert_eq!(service.to_object::<SomeType>(&data).unwrap(), value);
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_null() {
+        let service = SerializationService::default();
+        let data = Data(Bytes::copy_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0]));
+
+        assert!(service.to_object::<String>(&data).is_err());
+    }
+
+    #[test]
+    fn should_fail_to_read_null_array() {
+        let null = Bytes::from_static(&[255, 255, 255, 255]);
+
+        assert!(ObjectDataInput::new(null.clone()).read_i32_array().is_err());
+        assert!(ObjectDataInput::new(null.clone()).read_string().is_err());
+        assert!(ObjectDataInput::new(null).read_bytes().is_err());
+        assert!(ObjectDataInput::new(Bytes::from_static(&[0, 0, 0, 0]))
+            .read_i64_array()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_truncated_data() {
+        let service = SerializationService::default();
+        let data = Data(Bytes::copy_from_slice(&[0, 0, 0, 0, 255, 255, 255, 248, 0, 0]));
+
+        assert!(service.to_object::<i64>(&data).is_err());
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize_string() {
+        let service = SerializationService::default();
+        let value = "zażółć gęślą jaźń 🦀".to_string();
+
+        let data = service.to_data(&value);
+        assert_eq!(data.type_id(), STRING_TYPE_ID);
+        assert_eq!(service.to_object::<String>(&data).unwrap(), value);
+    }
+
+    #[test]
+    fn should_write_java_compatible_string() {
+        let data = SerializationService::default().to_data(&"ab".to_string());
+
+        assert_eq!(
+            data.0[..],
+            [
+                0, 0, 0, 0, // partition hash
+                255, 255, 255, 245, // type id
+                0, 0, 0, 2, // length
+                97, 98 // chars
+            ]
+        );
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_on_type_mismatch() {
+        let service = SerializationService::default();
+        let data = Data(Bytes::copy_from_slice(&[0, 0, 0, 0, 255, 255, 255, 248]));
+
+        assert!(service.to_object::<String>(&data).is_err());
+    }
+
+    #[test]
+    fn should_write_and_read_data() {
+        let data = SerializationService::default().to_data(&"value".to_string());
+
+        let writeable = &mut BytesMut::new();
+        data.write_to(writeable);
+
+        let readable = &mut Cursor::new(writeable.to_bytes());
+        assert_eq!(Data::read_from(readable).unwrap(), data);
+    }
+
+    #[test]
+    fn should_write_and_read_nested_data() {
+        let data = SerializationService::default().to_data(&"value".to_string());
+
+        let mut output = ObjectDataOutput::new();
+        output.write_data(&data);
+
+        let mut input = ObjectDataInput::new(output.buffer.to_bytes());
+        assert_eq!(input.read_data().unwrap(), data);
+        assert!(ObjectDataInput::new(Bytes::from_static(&[255, 255, 255, 255]))
+            .read_data()
+            .is_err());
+    }
+
+    #[test]
+    fn should_compute_murmur3_hash() {
+        assert_eq!(murmur3_32(b"", 0), 0);
+        assert_eq!(murmur3_32(b"hello", 0), 0x248b_fa47);
+        assert_eq!(
+            murmur3_32(b"The quick brown fox jumps over the lazy dog", 0),
+            0x2e4f_f723
+        );
+    }
+
+    #[test]
+    fn should_use_explicit_partition_hash() {
+        let data = Data(Bytes::copy_from_slice(&[0, 0, 0, 69, 255, 255, 255, 245, 0, 0, 0, 0]));
+
+        assert_eq!(data.partition_hash(), 69);
+    }
+
+    #[derive(Eq, PartialEq, Debug)]
+    struct SomeType {
+        id: i64,
+        name: String,
+    }
+
+    impl Serializer for SomeType {
+        fn type_id() -> i32 {
+            1000
+        }
+
+        fn write_to(&self, output: &mut ObjectDataOutput) {
+            output.write_i64(self.id);
+            output.write_string(&self.name);
+        }
+
+        fn read_from(input: &mut ObjectDataInput) -> Result<Self> {
+            Ok(SomeType {
+                id: input.read_i64()?,
+                name: input.read_string()?,
+            })
+        }
+    }
+}
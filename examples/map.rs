@@ -0,0 +1,64 @@
+use std::error::Error;
+
+use log::{info, LevelFilter};
+use log4rs::{
+    append::console::ConsoleAppender,
+    config::{Appender, Config, Root},
+};
+use rand::{distributions::Alphanumeric, thread_rng, Rng};
+
+use hazelcast_rust_client::HazelcastClient;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+    init_logger();
+
+    #[cfg(feature = "test-server")]
+    let cluster = hazelcast_rust_client::TestCluster::start(3).await?;
+    #[cfg(feature = "test-server")]
+    let addresses = cluster.addresses();
+    #[cfg(not(feature = "test-server"))]
+    let addresses = vec!["127.0.0.1:5701".parse()?];
+
+    let config = HazelcastClient::builder()
+        .addresses(addresses)
+        .group_name("dev")
+        .group_password("dev-pass")
+        .build();
+    let client = HazelcastClient::new(config).await?;
+
+    let map = client.map::<String, String>(&map_name());
+    let key = "key".to_string();
+
+    assert_eq!(map.get(&key).await?, None);
+    assert_eq!(map.put(&key, &"value".to_string()).await?, None);
+    assert_eq!(
+        map.put(&key, &"other-value".to_string()).await?,
+        Some("value".to_string())
+    );
+    assert!(map.contains_key(&key).await?);
+    assert_eq!(map.size().await?, 1);
+    assert_eq!(map.remove(&key).await?, Some("other-value".to_string()));
+    map.clear().await?;
+
+    info!("Size of {}: {}", map.name(), map.size().await?);
+
+    Ok(())
+}
+
+fn map_name() -> String {
+    format!(
+        "my-map-{}",
+        thread_rng().sample_iter(&Alphanumeric).take(8).collect::<String>()
+    )
+}
+
+fn init_logger() {
+    let _ = log4rs::init_config(
+        Config::builder()
+            .appender(Appender::builder().build("stdout", Box::new(ConsoleAppender::builder().build())))
+            .build(Root::builder().appender("stdout").build(LevelFilter::Info))
+            .unwrap(),
+    )
+    .unwrap();
+}
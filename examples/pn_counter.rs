@@ -6,7 +6,6 @@ use log4rs::{
     config::{Appender, Config, Root},
 };
 use rand::{distributions::Alphanumeric, thread_rng, Rng};
-use tokio;
 
 use hazelcast_rust_client::HazelcastClient;
 
@@ -14,7 +13,19 @@ use hazelcast_rust_client::HazelcastClient;
 async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     init_logger();
 
-    let client = HazelcastClient::new(vec!["127.0.0.1:5701".parse().unwrap()], "dev", "dev-pass").await?;
+    #[cfg(feature = "test-server")]
+    let cluster = hazelcast_rust_client::TestCluster::start(3).await?;
+    #[cfg(feature = "test-server")]
+    let addresses = cluster.addresses();
+    #[cfg(not(feature = "test-server"))]
+    let addresses = vec!["127.0.0.1:5701".parse()?];
+
+    let config = HazelcastClient::builder()
+        .addresses(addresses)
+        .group_name("dev")
+        .group_password("dev-pass")
+        .build();
+    let client = HazelcastClient::new(config).await?;
 
     let mut counter = client.pn_counter(&counter_name());
 
@@ -1,11 +1,11 @@
 extern crate proc_macro;
 
 use proc_macro2::TokenStream;
-use syn::{parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Fields, Lit, Meta, Type};
+use syn::{parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Field, Fields, Lit, Meta, Type};
 
 use quote::{quote, quote_spanned};
 
-#[proc_macro_derive(Request, attributes(r#type))]
+#[proc_macro_derive(Request, attributes(r#type, partition_id, retryable))]
 pub fn derive_request(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -23,16 +23,53 @@ fn request_body(input: &DeriveInput) -> TokenStream {
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = &input.generics.split_for_impl();
     let type_value = find_attribute_value("r#type", &input.attrs).expect("missing 'type' attribute!");
+    let partition_id_body = partition_id_body(&input.data);
+    let retryable_body = retryable_body(&input.attrs);
 
     quote! {
         impl #impl_generics crate::messaging::Request for #name #ty_generics #where_clause {
             fn r#type() -> u16 {
                 #type_value
             }
+
+            #partition_id_body
+
+            #retryable_body
         }
     }
 }
 
+fn retryable_body(attributes: &[Attribute]) -> TokenStream {
+    match attributes.iter().any(|attribute| attribute.path.is_ident("retryable")) {
+        true => quote! {
+            fn is_retryable() -> bool {
+                true
+            }
+        },
+        false => quote! {},
+    }
+}
+
+fn partition_id_body(data: &Data) -> TokenStream {
+    match *data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => match fields.named.iter().find(|field| is_partition_id(field)) {
+                Some(field) => {
+                    let name = &field.ident;
+                    quote_spanned! {field.span() =>
+                        fn partition_id(&self) -> i32 {
+                            self.#name
+                        }
+                    }
+                }
+                None => quote! {},
+            },
+            Fields::Unnamed(_) | Fields::Unit => unimplemented!(),
+        },
+        Data::Enum(_) | Data::Union(_) => unimplemented!(),
+    }
+}
+
 #[proc_macro_derive(Writer)]
 pub fn derive_writer(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -67,12 +104,16 @@ fn length_body(data: &Data) -> TokenStream {
     match *data {
         Data::Struct(ref data) => match data.fields {
             Fields::Named(ref fields) => {
-                let recurse = fields.named.iter().map(|field| {
-                    let name = &field.ident;
-                    quote_spanned! {field.span() =>
-                        self.#name.length()
-                    }
-                });
+                let recurse = fields
+                    .named
+                    .iter()
+                    .filter(|field| !is_partition_id(field))
+                    .map(|field| {
+                        let name = &field.ident;
+                        quote_spanned! {field.span() =>
+                            self.#name.length()
+                        }
+                    });
                 quote! {
                     0 #(+ #recurse)*
                 }
@@ -87,12 +128,16 @@ fn write_to_body(data: &Data) -> TokenStream {
     match *data {
         Data::Struct(ref data) => match data.fields {
             Fields::Named(ref fields) => {
-                let recurse = fields.named.iter().map(|field| {
-                    let name = &field.ident;
-                    quote_spanned! {field.span() =>
-                        self.#name.write_to(writeable);
-                    }
-                });
+                let recurse = fields
+                    .named
+                    .iter()
+                    .filter(|field| !is_partition_id(field))
+                    .map(|field| {
+                        let name = &field.ident;
+                        quote_spanned! {field.span() =>
+                            self.#name.write_to(writeable);
+                        }
+                    });
                 quote! {
                     #(#recurse)*
                 }
@@ -149,10 +194,10 @@ fn reader_body(input: &DeriveInput) -> TokenStream {
 
     quote! {
         impl #impl_generics crate::codec::Reader for #name #ty_generics #where_clause {
-            fn read_from(readable: &mut dyn crate::codec::Readable) -> Self {
-                #name {
+            fn read_from(readable: &mut dyn crate::codec::Readable) -> crate::codec::Result<Self> {
+                Ok(#name {
                     #read_from_body
-                }
+                })
             }
         }
     }
@@ -167,7 +212,7 @@ fn read_from_body(data: &Data) -> TokenStream {
                         let name = &field.ident;
                         let type_name = &type_path.path.segments.first().expect("missing first segment!").ident;
                         quote_spanned! {field.span() =>
-                            #name: #type_name::read_from(readable),
+                            #name: #type_name::read_from(readable)?,
                         }
                     }
                     Type::Array(_)
@@ -196,7 +241,14 @@ fn read_from_body(data: &Data) -> TokenStream {
     }
 }
 
-fn find_attribute_value(name: &str, attributes: &Vec<Attribute>) -> Option<Lit> {
+fn is_partition_id(field: &Field) -> bool {
+    field
+        .attrs
+        .iter()
+        .any(|attribute| attribute.path.is_ident("partition_id"))
+}
+
+fn find_attribute_value(name: &str, attributes: &[Attribute]) -> Option<Lit> {
     attributes
         .iter()
         .map(|attribute| attribute.parse_meta().expect("unable to parse attribute!"))